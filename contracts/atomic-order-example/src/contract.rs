@@ -2,16 +2,14 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{BankMsg, Coin, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, Uint128};
 use cw2::set_contract_version;
-use protobuf::Message;
-use std::str::FromStr;
 
 use injective_cosmwasm::{
     create_deposit_msg, create_spot_market_order_msg, create_withdraw_msg,
-    get_default_subaccount_id_for_checked_address, InjectiveMsgWrapper, InjectiveQuerier,
-    InjectiveQueryWrapper, OrderType, SpotOrder,
+    get_default_subaccount_id_for_checked_address, parse_create_spot_market_order_reply,
+    InjectiveMsgWrapper, InjectiveQuerier, InjectiveQueryWrapper, OrderType,
+    SpotMarketOrderResults, SpotOrder,
 };
 use injective_math::FPDecimal;
-use injective_protobuf::proto::tx;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg};
@@ -137,33 +135,12 @@ fn handle_atomic_order_reply(
     env: Env,
     msg: Reply,
 ) -> Result<Response<InjectiveMsgWrapper>, ContractError> {
-    let dec_scale_factor: FPDecimal = FPDecimal::from(1000000000000000000_i128);
-    let id = msg.id;
-    let order_response: tx::MsgCreateSpotMarketOrderResponse = Message::parse_from_bytes(
-        msg.result
-            .into_result()
-            .map_err(ContractError::SubMsgFailure)?
-            .data
-            .ok_or_else(|| ContractError::ReplyParseFailure {
-                id,
-                err: "Missing reply data".to_owned(),
-            })?
-            .as_slice(),
-    )
-    .map_err(|err| ContractError::ReplyParseFailure {
-        id,
-        err: err.to_string(),
-    })?;
-    // unwrap results into trade_data
-    let trade_data = match order_response.results.into_option() {
-        Some(trade_data) => Ok(trade_data),
-        None => Err(ContractError::CustomError {
-            val: "No trade data in order response".to_string(),
-        }),
-    }?;
-    let quantity = FPDecimal::from_str(&trade_data.quantity)? / dec_scale_factor;
-    let price = FPDecimal::from_str(&trade_data.price)? / dec_scale_factor;
-    let fee = FPDecimal::from_str(&trade_data.fee)? / dec_scale_factor;
+    let order_response = parse_create_spot_market_order_reply(&msg)?;
+    let SpotMarketOrderResults {
+        quantity,
+        price,
+        fee,
+    } = order_response.results;
 
     let config = STATE.load(deps.storage)?;
     let contract_address = env.contract.address;
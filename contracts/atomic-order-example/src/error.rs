@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use injective_cosmwasm::ReplyError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,18 +7,15 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Reply(#[from] ReplyError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
     #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
 
-    #[error("Failure response from submsg: {0}")]
-    SubMsgFailure(String),
-
     #[error("Unrecognised reply id: {0}")]
     UnrecognisedReply(u64),
-
-    #[error("Invalid reply from sub-message {id}, {err}")]
-    ReplyParseFailure { id: u64, err: String },
 }
@@ -6,11 +6,11 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use cw_storage_plus::Item;
 
-use injective_cosmwasm::{InjectiveMsgWrapper, InjectiveQueryWrapper};
+use injective_cosmwasm::{InjectiveMsgWrapper, InjectiveQueryWrapper, WasmxSudoMsg};
 
 use crate::error::ContractError;
 use crate::mock_pyth_attestation::execute_trigger_pyth_update;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:injective:dummy";
@@ -51,9 +51,9 @@ pub fn execute(
 }
 
 #[entry_point]
-pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+pub fn sudo(deps: DepsMut, _env: Env, msg: WasmxSudoMsg) -> Result<Response, ContractError> {
     match msg {
-        SudoMsg::BeginBlocker {} => {
+        WasmxSudoMsg::BeginBlocker {} => {
             for i in 1..10000 {
                 let _r = i * 2 / i * i / 3 * 2 * 7 / 7;
             }
@@ -61,6 +61,7 @@ pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, Contract
             COUNTER.save(deps.storage, &runs)?;
             Ok(Response::new())
         }
+        WasmxSudoMsg::Deregister {} | WasmxSudoMsg::Deactivate {} => Ok(Response::new()),
     }
 }
 
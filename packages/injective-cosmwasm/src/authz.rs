@@ -0,0 +1,378 @@
+use cosmwasm_std::{Addr, Binary, CosmosMsg, StdError, StdResult, Timestamp};
+use injective_protobuf::proto::authz::authz::{GenericAuthorization, Grant};
+use injective_protobuf::proto::authz::tx::{MsgExec, MsgGrant, MsgRevoke};
+use injective_protobuf::proto::exchange::authz as exchange_authz;
+use protobuf::well_known_types::{Any, Timestamp as ProtoTimestamp};
+use protobuf::{Message, RepeatedField, SingularPtrField};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::stargate::{encode_injective_msg, exchange_type_url, to_any, EXCHANGE_TYPE_URL_PREFIX};
+use crate::{InjectiveMsgWrapper, MarketId, SubaccountId};
+
+pub const MSG_GRANT_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgGrant";
+pub const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+pub const MSG_REVOKE_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgRevoke";
+pub const GENERIC_AUTHORIZATION_TYPE_URL: &str = "/cosmos.authz.v1beta1.GenericAuthorization";
+
+/// Exchange messages for which the exchange module offers a per-market authorization
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExchangeAuthzMsg {
+    CreateSpotLimitOrder,
+    CreateSpotMarketOrder,
+    BatchCreateSpotLimitOrders,
+    CancelSpotOrder,
+    BatchCancelSpotOrders,
+    CreateDerivativeLimitOrder,
+    CreateDerivativeMarketOrder,
+    BatchCreateDerivativeLimitOrders,
+    CancelDerivativeOrder,
+    BatchCancelDerivativeOrders,
+}
+
+impl ExchangeAuthzMsg {
+    fn name(&self) -> &'static str {
+        match self {
+            ExchangeAuthzMsg::CreateSpotLimitOrder => "CreateSpotLimitOrder",
+            ExchangeAuthzMsg::CreateSpotMarketOrder => "CreateSpotMarketOrder",
+            ExchangeAuthzMsg::BatchCreateSpotLimitOrders => "BatchCreateSpotLimitOrders",
+            ExchangeAuthzMsg::CancelSpotOrder => "CancelSpotOrder",
+            ExchangeAuthzMsg::BatchCancelSpotOrders => "BatchCancelSpotOrders",
+            ExchangeAuthzMsg::CreateDerivativeLimitOrder => "CreateDerivativeLimitOrder",
+            ExchangeAuthzMsg::CreateDerivativeMarketOrder => "CreateDerivativeMarketOrder",
+            ExchangeAuthzMsg::BatchCreateDerivativeLimitOrders => "BatchCreateDerivativeLimitOrders",
+            ExchangeAuthzMsg::CancelDerivativeOrder => "CancelDerivativeOrder",
+            ExchangeAuthzMsg::BatchCancelDerivativeOrders => "BatchCancelDerivativeOrders",
+        }
+    }
+
+    /// Type URL of the authorized message, e.g. `/injective.exchange.v1beta1.MsgCreateSpotLimitOrder`
+    pub fn msg_type_url(&self) -> String {
+        exchange_type_url(&format!("Msg{}", self.name()))
+    }
+
+    /// Type URL of the per-market authorization, e.g. `/injective.exchange.v1beta1.CreateSpotLimitOrderAuthz`
+    pub fn authz_type_url(&self) -> String {
+        exchange_type_url(&format!("{}Authz", self.name()))
+    }
+}
+
+/// Authorization a granter gives a grantee over exchange messages
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExchangeAuthorization {
+    /// Any message of an exchange message type URL, on any subaccount and market
+    Generic { msg_type_url: String },
+    /// `msg` on a single subaccount of the granter, restricted to `market_ids`
+    Market {
+        msg: ExchangeAuthzMsg,
+        subaccount_id: SubaccountId,
+        market_ids: Vec<MarketId>,
+    },
+    /// `MsgBatchUpdateOrders` on a single subaccount of the granter, restricted to the given spot and derivative markets
+    BatchUpdateOrders {
+        subaccount_id: SubaccountId,
+        spot_market_ids: Vec<MarketId>,
+        derivative_market_ids: Vec<MarketId>,
+    },
+}
+
+macro_rules! market_authz {
+    ($authz:ty, $subaccount_id:expr, $market_ids:expr) => {{
+        let mut authz = <$authz>::new();
+        authz.subaccount_id = $subaccount_id;
+        authz.market_ids = $market_ids;
+        authz
+    }};
+}
+
+impl ExchangeAuthorization {
+    fn to_any(&self) -> StdResult<Any> {
+        match self {
+            ExchangeAuthorization::Generic { msg_type_url } => {
+                if !msg_type_url.starts_with(EXCHANGE_TYPE_URL_PREFIX) {
+                    return Err(StdError::generic_err(format!(
+                        "Generic authorization is limited to exchange messages, got {msg_type_url}"
+                    )));
+                }
+                let mut authz = GenericAuthorization::new();
+                authz.msg = msg_type_url.to_owned();
+                to_any(GENERIC_AUTHORIZATION_TYPE_URL, &authz)
+            }
+            ExchangeAuthorization::Market {
+                msg,
+                subaccount_id,
+                market_ids,
+            } => {
+                if market_ids.is_empty() {
+                    return Err(StdError::generic_err("Market authorization requires at least one market"));
+                }
+                let type_url = msg.authz_type_url();
+                let subaccount_id = subaccount_id.as_str().to_string();
+                let market_ids = to_repeated_market_ids(market_ids);
+                match msg {
+                    ExchangeAuthzMsg::CreateSpotLimitOrder => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::CreateSpotLimitOrderAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::CreateSpotMarketOrder => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::CreateSpotMarketOrderAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::BatchCreateSpotLimitOrders => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::BatchCreateSpotLimitOrdersAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::CancelSpotOrder => {
+                        to_any(&type_url, &market_authz!(exchange_authz::CancelSpotOrderAuthz, subaccount_id, market_ids))
+                    }
+                    ExchangeAuthzMsg::BatchCancelSpotOrders => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::BatchCancelSpotOrdersAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::CreateDerivativeLimitOrder => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::CreateDerivativeLimitOrderAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::CreateDerivativeMarketOrder => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::CreateDerivativeMarketOrderAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::BatchCreateDerivativeLimitOrders => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::BatchCreateDerivativeLimitOrdersAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::CancelDerivativeOrder => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::CancelDerivativeOrderAuthz, subaccount_id, market_ids),
+                    ),
+                    ExchangeAuthzMsg::BatchCancelDerivativeOrders => to_any(
+                        &type_url,
+                        &market_authz!(exchange_authz::BatchCancelDerivativeOrdersAuthz, subaccount_id, market_ids),
+                    ),
+                }
+            }
+            ExchangeAuthorization::BatchUpdateOrders {
+                subaccount_id,
+                spot_market_ids,
+                derivative_market_ids,
+            } => {
+                if spot_market_ids.is_empty() && derivative_market_ids.is_empty() {
+                    return Err(StdError::generic_err("Market authorization requires at least one market"));
+                }
+                let mut authz = exchange_authz::BatchUpdateOrdersAuthz::new();
+                authz.subaccount_id = subaccount_id.as_str().to_string();
+                authz.spot_markets = to_repeated_market_ids(spot_market_ids);
+                authz.derivative_markets = to_repeated_market_ids(derivative_market_ids);
+                to_any(&exchange_type_url("BatchUpdateOrdersAuthz"), &authz)
+            }
+        }
+    }
+}
+
+fn to_repeated_market_ids(market_ids: &[MarketId]) -> RepeatedField<String> {
+    market_ids.iter().map(|market_id| market_id.as_str().to_string()).collect()
+}
+
+fn to_stargate_msg<M: Message>(type_url: &str, message: &M) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    let any = to_any(type_url, message)?;
+    Ok(CosmosMsg::Stargate {
+        type_url: any.type_url,
+        value: Binary::from(any.value),
+    })
+}
+
+/// Grants `grantee` the `authorization` over exchange messages of `granter`, optionally until `expiration`.
+/// The granter has to be the account executing the message, i.e. the contract itself.
+pub fn create_grant_msg(
+    granter: &Addr,
+    grantee: &Addr,
+    authorization: &ExchangeAuthorization,
+    expiration: Option<Timestamp>,
+) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    let mut grant = Grant::new();
+    grant.authorization = SingularPtrField::some(authorization.to_any()?);
+    if let Some(expiration) = expiration {
+        let mut timestamp = ProtoTimestamp::new();
+        timestamp.seconds = expiration.seconds() as i64;
+        timestamp.nanos = expiration.subsec_nanos() as i32;
+        grant.expiration = SingularPtrField::some(timestamp);
+    }
+
+    let mut msg = MsgGrant::new();
+    msg.granter = granter.to_string();
+    msg.grantee = grantee.to_string();
+    msg.grant = SingularPtrField::some(grant);
+    to_stargate_msg(MSG_GRANT_TYPE_URL, &msg)
+}
+
+/// Revokes the authorization `granter` gave `grantee` over messages of `msg_type_url`
+pub fn create_revoke_msg(granter: &Addr, grantee: &Addr, msg_type_url: &str) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    let mut msg = MsgRevoke::new();
+    msg.granter = granter.to_string();
+    msg.grantee = grantee.to_string();
+    msg.msg_type_url = msg_type_url.to_string();
+    to_stargate_msg(MSG_REVOKE_TYPE_URL, &msg)
+}
+
+/// Executes `msgs` on behalf of their senders, which must have granted `grantee` (usually the contract) the matching authorizations.
+/// Authz executes protobuf messages, so `msgs` have to be Stargate messages or custom Injective messages, which are encoded like `create_stargate_msg`.
+pub fn create_exec_msg(grantee: &Addr, msgs: &[CosmosMsg<InjectiveMsgWrapper>]) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    if msgs.is_empty() {
+        return Err(StdError::generic_err("MsgExec requires at least one message"));
+    }
+
+    let mut msg = MsgExec::new();
+    msg.grantee = grantee.to_string();
+    msg.msgs = msgs.iter().map(to_exec_any).collect::<StdResult<Vec<Any>>>()?.into();
+    to_stargate_msg(MSG_EXEC_TYPE_URL, &msg)
+}
+
+fn to_exec_any(msg: &CosmosMsg<InjectiveMsgWrapper>) -> StdResult<Any> {
+    match msg {
+        CosmosMsg::Stargate { type_url, value } => {
+            let mut any = Any::new();
+            any.type_url = type_url.to_owned();
+            any.value = value.to_vec();
+            Ok(any)
+        }
+        CosmosMsg::Custom(wrapper) => encode_injective_msg(&wrapper.msg_data),
+        msg => Err(StdError::generic_err(format!("MsgExec can only wrap Stargate messages, got {msg:?}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, BankMsg, Binary, Coin, CosmosMsg, StdError, Timestamp};
+    use injective_protobuf::proto::authz::authz::GenericAuthorization;
+    use injective_protobuf::proto::authz::tx::{MsgExec, MsgGrant};
+    use injective_protobuf::proto::exchange::authz::{BatchUpdateOrdersAuthz, CreateSpotMarketOrderAuthz};
+    use injective_protobuf::proto::exchange::tx::{MsgCreateSpotMarketOrder, MsgDeposit};
+    use protobuf::Message;
+
+    use crate::authz::{create_exec_msg, create_grant_msg, ExchangeAuthorization, ExchangeAuthzMsg, MSG_EXEC_TYPE_URL, MSG_GRANT_TYPE_URL};
+    use crate::{create_deposit_msg, MarketId, SubaccountId};
+
+    const SUBACCOUNT_ID: &str = "0xb5e09b93aceb70c1b47cb1e9e5a2d3d4a52d9d20000000000000000000000001";
+
+    fn decode_grant(msg: CosmosMsg<crate::InjectiveMsgWrapper>) -> MsgGrant {
+        match msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, MSG_GRANT_TYPE_URL);
+                MsgGrant::parse_from_bytes(value.as_slice()).unwrap()
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn grants_per_market_authorization() {
+        let authorization = ExchangeAuthorization::Market {
+            msg: ExchangeAuthzMsg::CreateSpotMarketOrder,
+            subaccount_id: SubaccountId::unchecked(SUBACCOUNT_ID),
+            market_ids: vec![MarketId::unchecked("0x01"), MarketId::unchecked("0x02")],
+        };
+        let msg = create_grant_msg(
+            &Addr::unchecked("inj1granter"),
+            &Addr::unchecked("inj1grantee"),
+            &authorization,
+            Some(Timestamp::from_seconds(1000)),
+        )
+        .unwrap();
+
+        let grant = decode_grant(msg);
+        assert_eq!(grant.granter, "inj1granter");
+        assert_eq!(grant.grantee, "inj1grantee");
+        assert_eq!(grant.get_grant().get_expiration().seconds, 1000);
+        let authorization = grant.get_grant().get_authorization();
+        assert_eq!(authorization.type_url, "/injective.exchange.v1beta1.CreateSpotMarketOrderAuthz");
+        let authz = CreateSpotMarketOrderAuthz::parse_from_bytes(&authorization.value).unwrap();
+        assert_eq!(authz.subaccount_id, SUBACCOUNT_ID);
+        assert_eq!(authz.market_ids.to_vec(), vec!["0x01".to_string(), "0x02".to_string()]);
+    }
+
+    #[test]
+    fn grants_batch_update_and_generic_authorizations() {
+        let authorization = ExchangeAuthorization::BatchUpdateOrders {
+            subaccount_id: SubaccountId::unchecked(SUBACCOUNT_ID),
+            spot_market_ids: vec![MarketId::unchecked("0x01")],
+            derivative_market_ids: vec![],
+        };
+        let grant = decode_grant(create_grant_msg(&Addr::unchecked("inj1granter"), &Addr::unchecked("inj1grantee"), &authorization, None).unwrap());
+        assert!(!grant.get_grant().has_expiration());
+        let authz = BatchUpdateOrdersAuthz::parse_from_bytes(&grant.get_grant().get_authorization().value).unwrap();
+        assert_eq!(authz.spot_markets.to_vec(), vec!["0x01".to_string()]);
+
+        let authorization = ExchangeAuthorization::Generic {
+            msg_type_url: ExchangeAuthzMsg::CancelSpotOrder.msg_type_url(),
+        };
+        let grant = decode_grant(create_grant_msg(&Addr::unchecked("inj1granter"), &Addr::unchecked("inj1grantee"), &authorization, None).unwrap());
+        let authorization = grant.get_grant().get_authorization();
+        assert_eq!(authorization.type_url, "/cosmos.authz.v1beta1.GenericAuthorization");
+        let authz = GenericAuthorization::parse_from_bytes(&authorization.value).unwrap();
+        assert_eq!(authz.msg, "/injective.exchange.v1beta1.MsgCancelSpotOrder");
+    }
+
+    #[test]
+    fn rejects_non_exchange_generic_authorization() {
+        let authorization = ExchangeAuthorization::Generic {
+            msg_type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+        };
+        let err = create_grant_msg(&Addr::unchecked("inj1granter"), &Addr::unchecked("inj1grantee"), &authorization, None).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Generic authorization is limited to exchange messages, got /cosmos.bank.v1beta1.MsgSend")
+        );
+    }
+
+    #[test]
+    fn exec_wraps_stargate_msgs() {
+        let mut order_msg = MsgCreateSpotMarketOrder::new();
+        order_msg.sender = "inj1granter".to_string();
+        let msgs = vec![CosmosMsg::Stargate {
+            type_url: ExchangeAuthzMsg::CreateSpotMarketOrder.msg_type_url(),
+            value: Binary::from(order_msg.write_to_bytes().unwrap()),
+        }];
+
+        match create_exec_msg(&Addr::unchecked("inj1grantee"), &msgs).unwrap() {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, MSG_EXEC_TYPE_URL);
+                let exec = MsgExec::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(exec.grantee, "inj1grantee");
+                assert_eq!(exec.msgs.len(), 1);
+                assert_eq!(exec.msgs[0].type_url, "/injective.exchange.v1beta1.MsgCreateSpotMarketOrder");
+                let order_msg = MsgCreateSpotMarketOrder::parse_from_bytes(&exec.msgs[0].value).unwrap();
+                assert_eq!(order_msg.sender, "inj1granter");
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+
+        let err = create_exec_msg(&Addr::unchecked("inj1grantee"), &[]).unwrap_err();
+        assert_eq!(err, StdError::generic_err("MsgExec requires at least one message"));
+
+        let err = create_exec_msg(&Addr::unchecked("inj1grantee"), &[BankMsg::Burn { amount: vec![] }.into()]).unwrap_err();
+        assert!(err.to_string().contains("MsgExec can only wrap Stargate messages"));
+    }
+
+    #[test]
+    fn exec_encodes_custom_injective_msgs() {
+        let deposit = create_deposit_msg(
+            Addr::unchecked("inj1granter"),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            Coin::new(1, "inj"),
+        );
+
+        match create_exec_msg(&Addr::unchecked("inj1grantee"), &[deposit]).unwrap() {
+            CosmosMsg::Stargate { value, .. } => {
+                let exec = MsgExec::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(exec.msgs[0].type_url, "/injective.exchange.v1beta1.MsgDeposit");
+                let deposit = MsgDeposit::parse_from_bytes(&exec.msgs[0].value).unwrap();
+                assert_eq!(deposit.sender, "inj1granter");
+                assert_eq!(deposit.subaccount_id, SUBACCOUNT_ID);
+                assert_eq!(deposit.get_amount().amount, "1");
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+    }
+}
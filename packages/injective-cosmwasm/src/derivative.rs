@@ -133,11 +133,17 @@ impl DerivativeOrder {
 
 impl GenericOrder for DerivativeOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        matches!(
+            self.order_type,
+            OrderType::Buy | OrderType::StopBuy | OrderType::TakeBuy | OrderType::BuyPo | OrderType::BuyAtomic
+        )
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        matches!(
+            self.order_type,
+            OrderType::Sell | OrderType::StopSell | OrderType::TakeSell | OrderType::SellPo | OrderType::SellAtomic
+        )
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -192,11 +198,17 @@ impl DerivativeLimitOrder {
 
 impl GenericOrder for DerivativeLimitOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        matches!(
+            self.order_type,
+            OrderType::Buy | OrderType::StopBuy | OrderType::TakeBuy | OrderType::BuyPo | OrderType::BuyAtomic
+        )
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        matches!(
+            self.order_type,
+            OrderType::Sell | OrderType::StopSell | OrderType::TakeSell | OrderType::SellPo | OrderType::SellAtomic
+        )
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -261,3 +273,26 @@ pub struct TrimmedDerivativeLimitOrder {
     pub isBuy: bool,
     pub order_hash: String,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PositionDelta {
+    pub is_long: bool,
+    pub execution_quantity: FPDecimal,
+    pub execution_margin: FPDecimal,
+    pub execution_price: FPDecimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DerivativeMarketOrderResults {
+    pub quantity: FPDecimal,
+    pub price: FPDecimal,
+    pub fee: FPDecimal,
+    pub position_delta: PositionDelta,
+    pub payout: FPDecimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MsgCreateDerivativeMarketOrderResponse {
+    pub order_hash: String,
+    pub results: DerivativeMarketOrderResults,
+}
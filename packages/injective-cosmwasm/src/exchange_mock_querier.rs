@@ -333,6 +333,10 @@ pub trait HandlesOraclePriceQuery {
     fn handle(&self, oracle_type: OracleType, base: String, quote: String) -> QuerierResult;
 }
 
+pub trait HandlesStargateQuery {
+    fn handle(&self, path: String, data: Binary) -> QuerierResult;
+}
+
 pub trait HandlesPythPriceQuery {
     fn handle(&self, price_id: String) -> QuerierResult;
 }
@@ -408,6 +412,7 @@ pub struct WasmMockQuerier {
     pub balance_query_handler: Option<Box<dyn HandlesBankBalanceQuery>>,
     pub all_balances_query_handler: Option<Box<dyn HandlesBankAllBalancesQuery>>,
     pub registered_contract_info_query_handler: Option<Box<dyn HandlesByAddressQuery>>,
+    pub stargate_query_handler: Option<Box<dyn HandlesStargateQuery>>,
 }
 
 impl Querier for WasmMockQuerier {
@@ -444,6 +449,11 @@ impl WasmMockQuerier {
                 },
                 _ => panic!("unsupported"),
             },
+            #[cfg(feature = "stargate")]
+            QueryRequest::Stargate { path, data } => match &self.stargate_query_handler {
+                Some(handler) => handler.handle(path.to_string(), data.to_owned()),
+                None => panic!("Unknown stargate query"),
+            },
             QueryRequest::Custom(query) => match query.query_data.clone() {
                 InjectiveQuery::SubaccountDeposit { subaccount_id, denom } => match &self.subaccount_deposit_response_handler {
                     Some(handler) => handler.handle(subaccount_id, denom),
@@ -623,6 +633,7 @@ impl WasmMockQuerier {
             balance_query_handler: None,
             all_balances_query_handler: None,
             registered_contract_info_query_handler: None,
+            stargate_query_handler: None,
             denom_decimals_handler: None,
         }
     }
@@ -658,9 +669,10 @@ pub mod handlers {
     use injective_math::FPDecimal;
 
     use crate::exchange_mock_querier::{HandlesByAddressQuery, HandlesDenomSupplyQuery, HandlesFeeQuery};
+    use crate::ibc::{DenomTrace, QueryDenomTraceResponse};
     use crate::query::{
-        OraclePriceResponse, PricePairState, QueryContractRegistrationInfoResponse, RegisteredContract, TokenFactoryCreateDenomFeeResponse,
-        TokenFactoryDenomSupplyResponse,
+        DenomDecimals, OraclePriceResponse, PricePairState, PythPriceResponse, PythPriceState, QueryContractRegistrationInfoResponse,
+        QueryDenomDecimalsResponse, RegisteredContract, TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse,
     };
     use crate::{
         exchange_mock_querier::TestCoin, Deposit, DerivativeMarket, DerivativeMarketResponse, EffectivePosition, FullDerivativeMarket,
@@ -675,7 +687,7 @@ pub mod handlers {
         OracleType,
     };
 
-    use super::{HandlesOraclePriceQuery, TestDeposit};
+    use super::{HandlesDenomDecimalsQuery, HandlesOraclePriceQuery, HandlesPythPriceQuery, HandlesStargateQuery, TestDeposit};
 
     pub fn create_subaccount_deposit_handler(coins: Vec<TestCoin>) -> Option<Box<dyn HandlesSubaccountAndDenomQuery>> {
         struct Temp {
@@ -1018,6 +1030,41 @@ pub mod handlers {
         }))
     }
 
+    pub fn create_denom_decimals_handler(denom_decimals: Vec<DenomDecimals>) -> Option<Box<dyn HandlesDenomDecimalsQuery>> {
+        struct Temp {
+            denom_decimals: Vec<DenomDecimals>,
+        }
+        impl HandlesDenomDecimalsQuery for Temp {
+            fn handle(&self, denoms: Vec<String>) -> QuerierResult {
+                let response = QueryDenomDecimalsResponse {
+                    denom_decimals: self
+                        .denom_decimals
+                        .iter()
+                        .filter(|denom_decimals| denoms.contains(&denom_decimals.denom))
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { denom_decimals }))
+    }
+
+    pub fn create_pyth_price_handler(pyth_price_state: Option<PythPriceState>) -> Option<Box<dyn HandlesPythPriceQuery>> {
+        struct Temp {
+            pyth_price_state: Option<PythPriceState>,
+        }
+        impl HandlesPythPriceQuery for Temp {
+            fn handle(&self, _: String) -> QuerierResult {
+                let response = PythPriceResponse {
+                    pyth_price_state: self.pyth_price_state.to_owned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { pyth_price_state }))
+    }
+
     pub fn create_denom_supply_handler(supply: Uint128) -> Option<Box<dyn HandlesDenomSupplyQuery>> {
         struct Temp {
             supply: Uint128,
@@ -1092,6 +1139,21 @@ pub mod handlers {
         Some(Box::new(Temp { balances }))
     }
 
+    pub fn create_ibc_denom_trace_handler(denom_trace: Option<DenomTrace>) -> Option<Box<dyn HandlesStargateQuery>> {
+        struct Temp {
+            denom_trace: Option<DenomTrace>,
+        }
+        impl HandlesStargateQuery for Temp {
+            fn handle(&self, _: String, _: Binary) -> QuerierResult {
+                let response = QueryDenomTraceResponse {
+                    denom_trace: self.denom_trace.to_owned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { denom_trace }))
+    }
+
     pub fn create_smart_query_handler(result: Result<Binary, SystemError>) -> Option<Box<dyn HandlesSmartQuery>> {
         struct Temp {
             result: Result<Binary, SystemError>,
@@ -0,0 +1,328 @@
+use cosmwasm_std::{to_vec, StdError, StdResult};
+#[cfg(feature = "stargate")]
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, IbcMsg, IbcTimeout};
+#[cfg(feature = "stargate")]
+use injective_protobuf::proto::ibc::{client::Height, coin::Coin as ProtoCoin, query::QueryDenomTraceRequest, tx::MsgTransfer};
+#[cfg(feature = "stargate")]
+use protobuf::{Message, SingularPtrField};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "stargate")]
+use crate::msg::create_withdraw_msg;
+#[cfg(feature = "stargate")]
+use crate::proto_conversion::ToProto;
+#[cfg(feature = "stargate")]
+use crate::subaccount::is_default_subaccount;
+#[cfg(feature = "stargate")]
+use crate::{InjectiveMsgWrapper, SubaccountId};
+
+pub const IBC_TRANSFER_PORT: &str = "transfer";
+pub const MSG_TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+pub const DENOM_TRACE_QUERY_PATH: &str = "/ibc.applications.transfer.v1.Query/DenomTrace";
+
+/// Origin of an IBC voucher denom: the port/channel hops it travelled through and its denom on the source chain
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenomTrace {
+    #[serde(default)]
+    pub path: String,
+    pub base_denom: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryDenomTraceResponse {
+    pub denom_trace: Option<DenomTrace>,
+}
+
+/// Forwarding instructions for the packet-forward middleware of the receiving chain
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PacketForward {
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+}
+
+#[derive(Serialize)]
+struct ForwardMemo<'a> {
+    forward: &'a PacketForward,
+}
+
+#[derive(Serialize)]
+struct WasmHook<'a, M: Serialize> {
+    contract: &'a str,
+    msg: &'a M,
+}
+
+#[derive(Serialize)]
+struct WasmHookMemo<'a, M: Serialize> {
+    wasm: WasmHook<'a, M>,
+}
+
+/// ICS-20 memo asking the receiving chain to forward the transfer, e.g. `{"forward":{"receiver":...,"port":"transfer","channel":...}}`
+pub fn create_packet_forward_memo(forward: &PacketForward) -> StdResult<String> {
+    to_json_string(&ForwardMemo { forward })
+}
+
+/// ICS-20 memo executing `msg` on `contract` once the transfer is received, e.g. `{"wasm":{"contract":...,"msg":{...}}}`
+pub fn create_wasm_hook_memo<M: Serialize>(contract: &str, msg: &M) -> StdResult<String> {
+    to_json_string(&WasmHookMemo {
+        wasm: WasmHook { contract, msg },
+    })
+}
+
+fn to_json_string<T: Serialize>(value: &T) -> StdResult<String> {
+    String::from_utf8(to_vec(value)?).map_err(StdError::from)
+}
+
+/// Sends `amount` from the `subaccount_id` of `sender` to `receiver` over the ICS-20 channel `channel_id`.
+/// Funds held in a non-default subaccount are withdrawn to the bank balance first. Without a memo the transfer is a plain
+/// `IbcMsg::Transfer`, with one it is sent as a Stargate `MsgTransfer` since `IbcMsg` cannot carry memos.
+#[cfg(feature = "stargate")]
+pub fn create_ibc_transfer_msgs(
+    sender: &Addr,
+    subaccount_id: &SubaccountId,
+    channel_id: &str,
+    receiver: &str,
+    amount: Coin,
+    timeout: IbcTimeout,
+    memo: Option<String>,
+) -> StdResult<Vec<CosmosMsg<InjectiveMsgWrapper>>> {
+    if amount.amount.is_zero() {
+        return Err(StdError::generic_err("Cannot transfer zero amount"));
+    }
+    if timeout.block().is_none() && timeout.timestamp().is_none() {
+        return Err(StdError::generic_err("IBC transfer requires a timeout height or timestamp"));
+    }
+
+    let mut messages = vec![];
+    if !is_default_subaccount(subaccount_id) {
+        messages.push(create_withdraw_msg(sender.to_owned(), subaccount_id.to_owned(), amount.to_owned()));
+    }
+
+    let transfer_msg = match memo {
+        None => CosmosMsg::Ibc(IbcMsg::Transfer {
+            channel_id: channel_id.to_string(),
+            to_address: receiver.to_string(),
+            amount,
+            timeout,
+        }),
+        Some(memo) => create_msg_transfer(sender, channel_id, receiver, amount, &timeout, memo)?,
+    };
+    messages.push(transfer_msg);
+
+    Ok(messages)
+}
+
+#[cfg(feature = "stargate")]
+fn create_msg_transfer(
+    sender: &Addr,
+    channel_id: &str,
+    receiver: &str,
+    amount: Coin,
+    timeout: &IbcTimeout,
+    memo: String,
+) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    let token: ProtoCoin = amount.to_proto();
+
+    let mut msg = MsgTransfer::new();
+    msg.source_port = IBC_TRANSFER_PORT.to_string();
+    msg.source_channel = channel_id.to_string();
+    msg.token = SingularPtrField::some(token);
+    msg.sender = sender.to_string();
+    msg.receiver = receiver.to_string();
+    if let Some(block) = timeout.block() {
+        let mut height = Height::new();
+        height.revision_number = block.revision;
+        height.revision_height = block.height;
+        msg.timeout_height = SingularPtrField::some(height);
+    }
+    msg.timeout_timestamp = timeout.timestamp().map_or(0, |timestamp| timestamp.nanos());
+    msg.memo = memo;
+
+    let value = msg
+        .write_to_bytes()
+        .map_err(|e| StdError::generic_err(format!("Failed to encode MsgTransfer: {e}")))?;
+
+    Ok(CosmosMsg::Stargate {
+        type_url: MSG_TRANSFER_TYPE_URL.to_string(),
+        value: Binary::from(value),
+    })
+}
+
+#[cfg(feature = "stargate")]
+pub(crate) fn encode_denom_trace_request(ibc_denom: &str) -> StdResult<Binary> {
+    let hash = ibc_denom.strip_prefix("ibc/").unwrap_or(ibc_denom);
+    if hash.is_empty() {
+        return Err(StdError::generic_err("IBC denom hash cannot be empty"));
+    }
+
+    let mut request = QueryDenomTraceRequest::new();
+    request.hash = hash.to_string();
+    let data = request
+        .write_to_bytes()
+        .map_err(|e| StdError::generic_err(format!("Failed to encode QueryDenomTraceRequest: {e}")))?;
+    Ok(Binary::from(data))
+}
+
+#[cfg(all(test, feature = "stargate"))]
+mod tests {
+    use cosmwasm_std::{Addr, Coin, CosmosMsg, IbcMsg, IbcTimeout, IbcTimeoutBlock, QuerierWrapper, StdError, Timestamp};
+    use injective_protobuf::proto::ibc::tx::MsgTransfer;
+    use protobuf::Message;
+    use serde::Serialize;
+
+    use crate::ibc::{create_ibc_transfer_msgs, create_packet_forward_memo, create_wasm_hook_memo, DenomTrace, PacketForward, MSG_TRANSFER_TYPE_URL};
+    use crate::{create_ibc_denom_trace_handler, mock_dependencies, InjectiveMsg, InjectiveMsgWrapper, InjectiveQuerier, SubaccountId};
+
+    const SENDER: &str = "inj1khsfhm2vm4k3dp8jaqnxfj4fz0uv9f9hmv9c8j";
+
+    fn default_subaccount() -> SubaccountId {
+        SubaccountId::unchecked("0xb5e09b93aceb70c1b47cb1e9e5a2d3d4a52d9d20000000000000000000000000")
+    }
+
+    fn trading_subaccount() -> SubaccountId {
+        SubaccountId::unchecked("0xb5e09b93aceb70c1b47cb1e9e5a2d3d4a52d9d20000000000000000000000001")
+    }
+
+    #[test]
+    fn transfer_from_default_subaccount() {
+        let timeout = IbcTimeout::with_timestamp(Timestamp::from_seconds(1000));
+        let msgs = create_ibc_transfer_msgs(
+            &Addr::unchecked(SENDER),
+            &default_subaccount(),
+            "channel-1",
+            "cosmos1receiver",
+            Coin::new(100, "usdt"),
+            timeout.clone(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            msgs,
+            vec![CosmosMsg::Ibc(IbcMsg::Transfer {
+                channel_id: "channel-1".to_string(),
+                to_address: "cosmos1receiver".to_string(),
+                amount: Coin::new(100, "usdt"),
+                timeout,
+            })]
+        );
+    }
+
+    #[test]
+    fn transfer_from_subaccount_with_memo() {
+        let memo = create_packet_forward_memo(&PacketForward {
+            receiver: "osmo1receiver".to_string(),
+            port: "transfer".to_string(),
+            channel: "channel-0".to_string(),
+            timeout: None,
+            retries: Some(2),
+        })
+        .unwrap();
+        assert_eq!(
+            memo,
+            r#"{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-0","retries":2}}"#
+        );
+
+        let timeout = IbcTimeout::with_both(IbcTimeoutBlock { revision: 4, height: 1200 }, Timestamp::from_nanos(1_000_000));
+        let msgs = create_ibc_transfer_msgs(
+            &Addr::unchecked(SENDER),
+            &trading_subaccount(),
+            "channel-1",
+            "cosmos1receiver",
+            Coin::new(100, "usdt"),
+            timeout,
+            Some(memo.to_owned()),
+        )
+        .unwrap();
+        assert_eq!(msgs.len(), 2);
+
+        match &msgs[0] {
+            CosmosMsg::Custom(InjectiveMsgWrapper {
+                msg_data: InjectiveMsg::Withdraw { subaccount_id, amount, .. },
+                ..
+            }) => {
+                assert_eq!(subaccount_id, &trading_subaccount());
+                assert_eq!(amount, &Coin::new(100, "usdt"));
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+
+        match &msgs[1] {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, MSG_TRANSFER_TYPE_URL);
+                let transfer = MsgTransfer::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(transfer.source_port, "transfer");
+                assert_eq!(transfer.source_channel, "channel-1");
+                assert_eq!(transfer.sender, SENDER);
+                assert_eq!(transfer.receiver, "cosmos1receiver");
+                assert_eq!(transfer.get_token().amount, "100");
+                assert_eq!(transfer.get_token().denom, "usdt");
+                assert_eq!(transfer.get_timeout_height().revision_number, 4);
+                assert_eq!(transfer.get_timeout_height().revision_height, 1200);
+                assert_eq!(transfer.timeout_timestamp, 1_000_000);
+                assert_eq!(transfer.memo, memo);
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn wasm_hook_memo() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        enum HookMsg {
+            Deposit { recipient: String },
+        }
+
+        let hook_msg = HookMsg::Deposit {
+            recipient: "inj1recipient".to_string(),
+        };
+        let memo = create_wasm_hook_memo("inj1contract", &hook_msg).unwrap();
+        assert_eq!(
+            memo,
+            r#"{"wasm":{"contract":"inj1contract","msg":{"deposit":{"recipient":"inj1recipient"}}}}"#
+        );
+    }
+
+    #[test]
+    fn transfer_rejects_zero_amount() {
+        let err = create_ibc_transfer_msgs(
+            &Addr::unchecked(SENDER),
+            &default_subaccount(),
+            "channel-1",
+            "cosmos1receiver",
+            Coin::new(0, "usdt"),
+            IbcTimeout::with_timestamp(Timestamp::from_seconds(1000)),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Cannot transfer zero amount"));
+    }
+
+    #[test]
+    fn query_denom_trace() {
+        let mut deps = mock_dependencies();
+        deps.querier.stargate_query_handler = create_ibc_denom_trace_handler(Some(DenomTrace {
+            path: "transfer/channel-1".to_string(),
+            base_denom: "uatom".to_string(),
+        }));
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let ibc_denom = "ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9".to_string();
+        let trace = querier.query_ibc_denom_trace(&ibc_denom).unwrap();
+        assert_eq!(trace.base_denom, "uatom");
+        assert_eq!(trace.path, "transfer/channel-1");
+
+        deps.querier.stargate_query_handler = create_ibc_denom_trace_handler(None);
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+        let err = querier.query_ibc_denom_trace(&ibc_denom).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { .. }));
+    }
+}
@@ -1,5 +1,11 @@
+#[cfg(feature = "stargate")]
+pub use authz::{
+    create_exec_msg, create_grant_msg, create_revoke_msg, ExchangeAuthorization, ExchangeAuthzMsg, GENERIC_AUTHORIZATION_TYPE_URL, MSG_EXEC_TYPE_URL,
+    MSG_GRANT_TYPE_URL, MSG_REVOKE_TYPE_URL,
+};
 pub use derivative::{
-    DerivativeLimitOrder, DerivativeMarketOrder, DerivativeOrder, DerivativePosition, EffectivePosition, Position, TrimmedDerivativeLimitOrder,
+    DerivativeLimitOrder, DerivativeMarketOrder, DerivativeMarketOrderResults, DerivativeOrder, DerivativePosition, EffectivePosition,
+    MsgCreateDerivativeMarketOrderResponse, Position, PositionDelta, TrimmedDerivativeLimitOrder,
 };
 pub use derivative_market::{
     DerivativeMarket, FullDerivativeMarket, FullDerivativeMarketPerpetualInfo, PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState,
@@ -9,48 +15,93 @@ pub use exchange::Deposit;
 pub use exchange_mock_querier::handlers::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exchange_mock_querier::*;
+#[cfg(feature = "stargate")]
+pub use ibc::create_ibc_transfer_msgs;
+pub use ibc::{
+    create_packet_forward_memo, create_wasm_hook_memo, DenomTrace, PacketForward, QueryDenomTraceResponse, DENOM_TRACE_QUERY_PATH, IBC_TRANSFER_PORT,
+    MSG_TRANSFER_TYPE_URL,
+};
+pub use market_decimals::{to_base_units, SpotMarketDecimals};
 pub use msg::{
     cancel_derivative_order_msg, cancel_spot_order_msg, create_activate_contract_msg, create_batch_update_orders_msg, create_burn_tokens_msg,
     create_deactivate_contract_msg, create_deposit_msg, create_derivative_market_order_msg, create_external_transfer_msg,
     create_increase_position_margin_msg, create_liquidate_position_msg, create_mint_tokens_msg, create_new_denom_msg, create_register_as_dmm_msg,
-    create_relay_pyth_prices_msg, create_set_token_metadata_msg, create_spot_market_order_msg, create_subaccount_transfer_msg,
-    create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
+    create_relay_price_feed_price_msg, create_relay_provider_prices_msg, create_relay_pyth_prices_msg, create_request_band_ibc_rates_msg,
+    create_set_token_metadata_msg, create_spot_market_order_msg, create_subaccount_transfer_msg, create_update_contract_msg, create_withdraw_msg,
+    InjectiveMsg, InjectiveMsgWrapper,
 };
 pub use oracle::{OracleInfo, OracleType, PriceAttestation, PythStatus};
-pub use order::{GenericOrder, OrderData, OrderInfo, OrderType};
+pub use oracle_price::{
+    query_derivative_market_oracle_price, query_market_denom_decimals, query_oracle_price_with_info, query_spot_market_oracle_price,
+    scale_by_power_of_ten, scale_oracle_price,
+};
+pub use order::{GenericOrder, MsgBatchUpdateOrdersResponse, OrderData, OrderInfo, OrderType};
+#[cfg(feature = "stargate")]
+pub use proto_conversion::{FromProto, ToProto};
+pub use pyth::{scale_pyth_value, PythPriceError, PythPriceGuard, ValidatedPythPrice};
 pub use querier::InjectiveQuerier;
 pub use query::{
-    DenomDecimals, DerivativeMarketResponse, InjectiveQuery, InjectiveQueryWrapper, MarketMidPriceAndTOBResponse, MarketVolatilityResponse,
-    OraclePriceResponse, OracleVolatilityResponse, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PricePairState, PythPriceResponse,
-    QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse, SpotMarketResponse,
+    twap_from_cumulative_prices, DenomDecimals, DerivativeMarketResponse, InjectiveQuery, InjectiveQueryWrapper, MarketMidPriceAndTOBResponse,
+    MarketVolatilityResponse, OraclePriceHistoryResponse, OraclePriceResponse, OracleVolatilityResponse, PerpetualMarketFundingResponse,
+    PerpetualMarketInfoResponse, PricePairState, PriceState, PythPriceResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
+    QueryContractRegistrationInfoResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse, RegisteredContract, SpotMarketResponse,
     SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TokenFactoryDenomSupplyResponse,
     TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, FROM_WORST_TO_BEST_CANCELLATION_STRATEGY, UNSORTED_CANCELLATION_STRATEGY,
 };
+#[cfg(feature = "stargate")]
+pub use reply::{
+    parse_batch_update_orders_reply, parse_create_denom_reply, parse_create_derivative_market_order_reply, parse_create_spot_limit_order_reply,
+    parse_create_spot_market_order_reply, parse_tokenfactory_empty_reply, MsgCreateDenomResponse, ReplyError,
+};
 pub use route::InjectiveRoute;
-pub use spot::{MsgCreateSpotMarketOrderResponse, SpotLimitOrder, SpotMarketOrder, SpotOrder, TrimmedSpotLimitOrder};
+pub use spot::{
+    MsgCreateSpotLimitOrderResponse, MsgCreateSpotMarketOrderResponse, SpotLimitOrder, SpotMarketOrder, SpotMarketOrderResults, SpotOrder,
+    TrimmedSpotLimitOrder,
+};
 pub use spot_market::SpotMarket;
+#[cfg(feature = "stargate")]
+pub use stargate::{
+    create_stargate_msg, to_stargate_msg, EXCHANGE_TYPE_URL_PREFIX, ORACLE_TYPE_URL_PREFIX, TOKENFACTORY_TYPE_URL_PREFIX, WASMX_TYPE_URL_PREFIX,
+};
 pub use subaccount::{
     addr_to_bech32, bech32_to_hex, checked_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, is_default_subaccount,
     subaccount_id_to_ethereum_address, subaccount_id_to_injective_address, subaccount_id_to_unchecked_injective_address,
 };
 pub use types::{Hash, MarketId, MarketType, SubaccountId};
-pub use volatility::{MetadataStatistics, PriceRecord, TradeHistoryOptions, TradeRecord};
+pub use volatility::{
+    annualized_realized_volatility, price_series, time_weighted_average_price, MetadataStatistics, PriceRecord, TimestampedPrice,
+    TradeHistoryOptions, TradeRecord,
+};
+pub use wasmx::{query_begin_blocker_gas_budget, GasBudget, WasmxSudoMsg};
 
+#[cfg(feature = "stargate")]
+mod authz;
 mod derivative;
 mod derivative_market;
 mod exchange;
+mod ibc;
+mod market_decimals;
 mod msg;
 mod oracle;
+mod oracle_price;
 mod order;
 pub mod privileged_action;
+#[cfg(feature = "stargate")]
+mod proto_conversion;
+mod pyth;
 mod querier;
 mod query;
+#[cfg(feature = "stargate")]
+mod reply;
 mod route;
 mod spot;
 mod spot_market;
+#[cfg(feature = "stargate")]
+mod stargate;
 mod subaccount;
 mod types;
 mod volatility;
+mod wasmx;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod exchange_mock_querier;
@@ -0,0 +1,188 @@
+use cosmwasm_std::{Coin, StdError, StdResult, Uint128};
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::oracle_price::{query_market_denom_decimals, scale_by_power_of_ten};
+use crate::{InjectiveQuerier, SpotMarket};
+
+/// Converts spot market prices and quantities between human units (e.g. 1.5 INJ at 20 USDT),
+/// chain base units (`Uint128`/`Coin` amounts) and the exchange-internal `FPDecimal` representation.
+///
+/// The exchange module quotes quantities in base denom base units and prices in quote base units per base base unit,
+/// so a human price is scaled by `10^(quote_decimals - base_decimals)` and a human quantity by `10^base_decimals`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpotMarketDecimals {
+    pub base_denom: String,
+    pub quote_denom: String,
+    pub base_decimals: i32,
+    pub quote_decimals: i32,
+}
+
+impl SpotMarketDecimals {
+    pub fn new(market: &SpotMarket, base_decimals: i32, quote_decimals: i32) -> Self {
+        SpotMarketDecimals {
+            base_denom: market.base_denom.to_owned(),
+            quote_denom: market.quote_denom.to_owned(),
+            base_decimals,
+            quote_decimals,
+        }
+    }
+
+    /// Loads the base and quote denom decimals of `market` with the `DenomDecimals` query
+    pub fn query(querier: &InjectiveQuerier, market: &SpotMarket) -> StdResult<Self> {
+        let (base_decimals, quote_decimals) = query_market_denom_decimals(querier, market)?;
+        Ok(SpotMarketDecimals::new(market, base_decimals, quote_decimals))
+    }
+
+    pub fn human_price_to_chain(&self, price: FPDecimal) -> StdResult<FPDecimal> {
+        scale_by_power_of_ten(price, self.quote_decimals - self.base_decimals)
+    }
+
+    pub fn chain_price_to_human(&self, price: FPDecimal) -> StdResult<FPDecimal> {
+        scale_by_power_of_ten(price, self.base_decimals - self.quote_decimals)
+    }
+
+    pub fn human_quantity_to_chain(&self, quantity: FPDecimal) -> StdResult<FPDecimal> {
+        scale_by_power_of_ten(quantity, self.base_decimals)
+    }
+
+    pub fn chain_quantity_to_human(&self, quantity: FPDecimal) -> StdResult<FPDecimal> {
+        scale_by_power_of_ten(quantity, -self.base_decimals)
+    }
+
+    pub fn human_notional_to_chain(&self, notional: FPDecimal) -> StdResult<FPDecimal> {
+        scale_by_power_of_ten(notional, self.quote_decimals)
+    }
+
+    pub fn chain_notional_to_human(&self, notional: FPDecimal) -> StdResult<FPDecimal> {
+        scale_by_power_of_ten(notional, -self.quote_decimals)
+    }
+
+    /// Base denom coin worth `quantity` human units, rounded down to whole base units
+    pub fn base_coin(&self, quantity: FPDecimal) -> StdResult<Coin> {
+        let amount = to_base_units(self.human_quantity_to_chain(quantity)?)?;
+        Ok(Coin::new(amount.u128(), self.base_denom.to_owned()))
+    }
+
+    /// Quote denom coin worth `notional` human units, rounded down to whole base units
+    pub fn quote_coin(&self, notional: FPDecimal) -> StdResult<Coin> {
+        let amount = to_base_units(self.human_notional_to_chain(notional)?)?;
+        Ok(Coin::new(amount.u128(), self.quote_denom.to_owned()))
+    }
+
+    /// Human quantity of a base or quote denom coin of the market
+    pub fn coin_to_human(&self, coin: &Coin) -> StdResult<FPDecimal> {
+        let amount = FPDecimal::from(coin.amount);
+        if coin.denom == self.base_denom {
+            self.chain_quantity_to_human(amount)
+        } else if coin.denom == self.quote_denom {
+            self.chain_notional_to_human(amount)
+        } else {
+            Err(StdError::generic_err(format!(
+                "Denom {} is neither base denom {} nor quote denom {}",
+                coin.denom, self.base_denom, self.quote_denom
+            )))
+        }
+    }
+}
+
+/// Rounds an exchange-internal amount down to whole chain base units
+pub fn to_base_units(amount: FPDecimal) -> StdResult<Uint128> {
+    if amount.is_negative() {
+        return Err(StdError::generic_err(format!("Cannot convert negative amount {amount} to base units")));
+    }
+    if amount > FPDecimal::from(u128::MAX) {
+        return Err(StdError::generic_err(format!("Amount {amount} overflows base units")));
+    }
+    Ok(Uint128::from(amount.int()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Coin, QuerierWrapper, StdError, Uint128};
+    use injective_math::FPDecimal;
+
+    use crate::market_decimals::{to_base_units, SpotMarketDecimals};
+    use crate::query::DenomDecimals;
+    use crate::{create_denom_decimals_handler, mock_dependencies, InjectiveQuerier, MarketId, SpotMarket};
+
+    fn spot_market() -> SpotMarket {
+        SpotMarket {
+            ticker: "INJ/USDT".to_string(),
+            base_denom: "inj".to_string(),
+            quote_denom: "usdt".to_string(),
+            maker_fee_rate: FPDecimal::zero(),
+            taker_fee_rate: FPDecimal::zero(),
+            relayer_fee_share_rate: FPDecimal::zero(),
+            market_id: MarketId::unchecked("0x01"),
+            status: 1,
+            min_price_tick_size: FPDecimal::zero(),
+            min_quantity_tick_size: FPDecimal::zero(),
+        }
+    }
+
+    fn dec(value: &str) -> FPDecimal {
+        FPDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn converts_prices_and_quantities_both_ways() {
+        let decimals = SpotMarketDecimals::new(&spot_market(), 18, 6);
+
+        let chain_price = decimals.human_price_to_chain(dec("20.5")).unwrap();
+        assert_eq!(chain_price, dec("0.0000000000205"));
+        assert_eq!(decimals.chain_price_to_human(chain_price).unwrap(), dec("20.5"));
+
+        let chain_quantity = decimals.human_quantity_to_chain(dec("1.5")).unwrap();
+        assert_eq!(chain_quantity, FPDecimal::from(1_500_000_000_000_000_000u128));
+        assert_eq!(decimals.chain_quantity_to_human(chain_quantity).unwrap(), dec("1.5"));
+
+        // chain price times chain quantity is a quote amount in base units
+        assert_eq!(chain_price * chain_quantity, FPDecimal::from(30_750_000u128));
+        assert_eq!(decimals.chain_notional_to_human(chain_price * chain_quantity).unwrap(), dec("30.75"));
+    }
+
+    #[test]
+    fn converts_coins() {
+        let decimals = SpotMarketDecimals::new(&spot_market(), 18, 6);
+
+        assert_eq!(decimals.base_coin(dec("1.5")).unwrap(), Coin::new(1_500_000_000_000_000_000, "inj"));
+        assert_eq!(decimals.quote_coin(dec("30.7500009")).unwrap(), Coin::new(30_750_000, "usdt"));
+        assert_eq!(decimals.coin_to_human(&Coin::new(30_750_000, "usdt")).unwrap(), dec("30.75"));
+        assert!(decimals.coin_to_human(&Coin::new(1, "atom")).is_err());
+        assert!(decimals.base_coin(FPDecimal::MAX).is_err());
+        assert!(decimals.human_quantity_to_chain(FPDecimal::MAX).is_err());
+    }
+
+    #[test]
+    fn to_base_units_rejects_negative_amounts() {
+        assert_eq!(to_base_units(dec("10.9")).unwrap(), Uint128::from(10u128));
+        assert_eq!(
+            to_base_units(dec("-1")).unwrap_err(),
+            StdError::generic_err("Cannot convert negative amount -1 to base units")
+        );
+    }
+
+    #[test]
+    fn queries_market_decimals() {
+        let mut deps = mock_dependencies();
+        deps.querier.denom_decimals_handler = create_denom_decimals_handler(vec![
+            DenomDecimals {
+                denom: "inj".to_string(),
+                decimals: 18,
+            },
+            DenomDecimals {
+                denom: "usdt".to_string(),
+                decimals: 6,
+            },
+        ]);
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let decimals = SpotMarketDecimals::query(&querier, &spot_market()).unwrap();
+        assert_eq!(decimals, SpotMarketDecimals::new(&spot_market(), 18, 6));
+    }
+}
@@ -2,6 +2,8 @@ use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, CustomMsg, Deps, StdError, St
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use injective_math::FPDecimal;
+
 use crate::subaccount::is_default_subaccount;
 use crate::{derivative::DerivativeOrder, oracle::PriceAttestation, order::OrderData, route::InjectiveRoute, spot::SpotOrder};
 use crate::{subaccount_id_to_injective_address, InjectiveQueryWrapper, MarketId, SubaccountId};
@@ -99,6 +101,23 @@ pub enum InjectiveMsg {
         sender: Addr,
         price_attestations: Vec<PriceAttestation>,
     },
+    RelayPriceFeedPrice {
+        sender: Addr,
+        base: Vec<String>,
+        quote: Vec<String>,
+        price: Vec<FPDecimal>,
+    },
+    RelayProviderPrices {
+        sender: Addr,
+        provider: String,
+        symbols: Vec<String>,
+        prices: Vec<FPDecimal>,
+    },
+    #[serde(rename = "request_band_ibc_rates")]
+    RequestBandIBCRates {
+        sender: Addr,
+        request_id: u64,
+    },
     CreateDenom {
         sender: String,
         subdenom: String,
@@ -367,6 +386,87 @@ pub fn create_relay_pyth_prices_msg(sender: Addr, price_attestations: Vec<PriceA
     .into()
 }
 
+pub fn create_relay_price_feed_price_msg(
+    sender: Addr,
+    base: Vec<String>,
+    quote: Vec<String>,
+    price: Vec<FPDecimal>,
+) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    if base.len() != quote.len() || base.len() != price.len() {
+        return Err(StdError::generic_err(format!(
+            "Mismatched price feed lengths: {} bases, {} quotes, {} prices",
+            base.len(),
+            quote.len(),
+            price.len()
+        )));
+    }
+    validate_symbols(&base)?;
+    validate_symbols(&quote)?;
+    validate_prices(&price)?;
+
+    Ok(InjectiveMsgWrapper {
+        route: InjectiveRoute::Oracle,
+        msg_data: InjectiveMsg::RelayPriceFeedPrice { sender, base, quote, price },
+    }
+    .into())
+}
+
+pub fn create_relay_provider_prices_msg(
+    sender: Addr,
+    provider: String,
+    symbols: Vec<String>,
+    prices: Vec<FPDecimal>,
+) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    if provider.trim().is_empty() {
+        return Err(StdError::generic_err("Provider cannot be empty"));
+    }
+    if symbols.len() != prices.len() {
+        return Err(StdError::generic_err(format!(
+            "Mismatched provider prices lengths: {} symbols, {} prices",
+            symbols.len(),
+            prices.len()
+        )));
+    }
+    validate_symbols(&symbols)?;
+    validate_prices(&prices)?;
+
+    Ok(InjectiveMsgWrapper {
+        route: InjectiveRoute::Oracle,
+        msg_data: InjectiveMsg::RelayProviderPrices {
+            sender,
+            provider,
+            symbols,
+            prices,
+        },
+    }
+    .into())
+}
+
+pub fn create_request_band_ibc_rates_msg(sender: Addr, request_id: u64) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Oracle,
+        msg_data: InjectiveMsg::RequestBandIBCRates { sender, request_id },
+    }
+    .into()
+}
+
+fn validate_symbols(symbols: &[String]) -> StdResult<()> {
+    if symbols.is_empty() {
+        return Err(StdError::generic_err("At least one price must be relayed"));
+    }
+    if symbols.iter().any(|symbol| symbol.trim().is_empty()) {
+        return Err(StdError::generic_err("Symbols cannot be empty"));
+    }
+    Ok(())
+}
+
+fn validate_prices(prices: &[FPDecimal]) -> StdResult<()> {
+    if let Some(price) = prices.iter().find(|price| price.is_negative() || price.is_zero()) {
+        return Err(StdError::generic_err(format!("Invalid price {price}: prices must be positive")));
+    }
+    Ok(())
+}
+
 pub fn create_mint_tokens_msg(sender: Addr, amount: Coin, mint_to: String) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Tokenfactory,
@@ -443,3 +543,141 @@ pub fn create_deactivate_contract_msg(sender: Addr, contract_address: Addr) -> C
     }
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Addr, CosmosMsg, StdError};
+
+    use injective_math::FPDecimal;
+
+    use crate::msg::{create_relay_price_feed_price_msg, create_relay_provider_prices_msg, create_request_band_ibc_rates_msg};
+    use crate::{InjectiveMsg, InjectiveMsgWrapper, InjectiveRoute};
+
+    fn assert_json_round_trip(msg: CosmosMsg<InjectiveMsgWrapper>, json: &str) {
+        let wrapper = match msg {
+            CosmosMsg::Custom(wrapper) => wrapper,
+            _ => panic!("Expected custom message"),
+        };
+        assert_eq!(serde_json_wasm::to_string(&wrapper).unwrap(), json);
+        assert_eq!(serde_json_wasm::from_str::<InjectiveMsgWrapper>(json).unwrap(), wrapper);
+    }
+
+    #[test]
+    fn relay_price_feed_price_msg() {
+        let msg = create_relay_price_feed_price_msg(
+            Addr::unchecked("relayer"),
+            vec!["INJ".to_string()],
+            vec!["USDT".to_string()],
+            vec![FPDecimal::from_str("8.25").unwrap()],
+        )
+        .unwrap();
+
+        match msg {
+            CosmosMsg::Custom(wrapper) => {
+                assert_eq!(wrapper.route, InjectiveRoute::Oracle);
+                assert_eq!(
+                    wrapper.msg_data,
+                    InjectiveMsg::RelayPriceFeedPrice {
+                        sender: Addr::unchecked("relayer"),
+                        base: vec!["INJ".to_string()],
+                        quote: vec!["USDT".to_string()],
+                        price: vec![FPDecimal::from_str("8.25").unwrap()],
+                    }
+                );
+            }
+            _ => panic!("Expected custom message"),
+        }
+    }
+
+    #[test]
+    fn relay_price_feed_price_msg_validation() {
+        let err = create_relay_price_feed_price_msg(
+            Addr::unchecked("relayer"),
+            vec!["INJ".to_string(), "ATOM".to_string()],
+            vec!["USDT".to_string()],
+            vec![FPDecimal::one()],
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Mismatched price feed lengths: 2 bases, 1 quotes, 1 prices"));
+
+        let err = create_relay_price_feed_price_msg(Addr::unchecked("relayer"), vec![], vec![], vec![]).unwrap_err();
+        assert_eq!(err, StdError::generic_err("At least one price must be relayed"));
+
+        let err = create_relay_price_feed_price_msg(
+            Addr::unchecked("relayer"),
+            vec!["INJ".to_string()],
+            vec!["USDT".to_string()],
+            vec![FPDecimal::NEGATIVE_ONE],
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Invalid price -1: prices must be positive"));
+    }
+
+    #[test]
+    fn relay_provider_prices_msg_validation() {
+        let err = create_relay_provider_prices_msg(
+            Addr::unchecked("relayer"),
+            "".to_string(),
+            vec!["INJ".to_string()],
+            vec![FPDecimal::one()],
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Provider cannot be empty"));
+
+        let err = create_relay_provider_prices_msg(
+            Addr::unchecked("relayer"),
+            "provider".to_string(),
+            vec!["INJ".to_string(), " ".to_string()],
+            vec![FPDecimal::one(), FPDecimal::one()],
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Symbols cannot be empty"));
+
+        let err = create_relay_provider_prices_msg(
+            Addr::unchecked("relayer"),
+            "provider".to_string(),
+            vec!["INJ".to_string()],
+            vec![FPDecimal::zero()],
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Invalid price 0: prices must be positive"));
+
+        assert!(create_relay_provider_prices_msg(
+            Addr::unchecked("relayer"),
+            "provider".to_string(),
+            vec!["INJ".to_string()],
+            vec![FPDecimal::one()],
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn oracle_relay_msgs_json() {
+        assert_json_round_trip(
+            create_relay_price_feed_price_msg(
+                Addr::unchecked("relayer"),
+                vec!["INJ".to_string()],
+                vec!["USDT".to_string()],
+                vec![FPDecimal::from_str("8.25").unwrap()],
+            )
+            .unwrap(),
+            r#"{"route":"oracle","msg_data":{"relay_price_feed_price":{"sender":"relayer","base":["INJ"],"quote":["USDT"],"price":["8.25"]}}}"#,
+        );
+        assert_json_round_trip(
+            create_relay_provider_prices_msg(
+                Addr::unchecked("relayer"),
+                "provider".to_string(),
+                vec!["INJ".to_string()],
+                vec![FPDecimal::from_str("8.25").unwrap()],
+            )
+            .unwrap(),
+            r#"{"route":"oracle","msg_data":{"relay_provider_prices":{"sender":"relayer","provider":"provider","symbols":["INJ"],"prices":["8.25"]}}}"#,
+        );
+        assert_json_round_trip(
+            create_request_band_ibc_rates_msg(Addr::unchecked("relayer"), 7),
+            r#"{"route":"oracle","msg_data":{"request_band_ibc_rates":{"sender":"relayer","request_id":7}}}"#,
+        );
+    }
+}
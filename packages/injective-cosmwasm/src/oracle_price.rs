@@ -0,0 +1,271 @@
+use std::convert::TryFrom;
+
+use cosmwasm_std::{StdError, StdResult};
+use injective_math::FPDecimal;
+
+use crate::market_decimals::SpotMarketDecimals;
+use crate::oracle::{OracleInfo, OracleType};
+use crate::{DerivativeMarket, InjectiveQuerier, SpotMarket};
+
+/// Multiplies `value` by `10^exponent`, dividing for negative exponents. Fails instead of overflowing
+pub fn scale_by_power_of_ten(value: FPDecimal, exponent: i32) -> StdResult<FPDecimal> {
+    let scale = 10u128
+        .checked_pow(exponent.unsigned_abs())
+        .ok_or_else(|| StdError::generic_err(format!("Scale exponent {exponent} out of range")))?;
+
+    if exponent < 0 {
+        value.checked_div(FPDecimal::from(scale))
+    } else {
+        Ok(value.checked_mul(FPDecimal::from(scale))?)
+    }
+}
+
+/// Applies an oracle scale factor to a raw oracle price, the same way the exchange module prices derivative markets
+pub fn scale_oracle_price(price: FPDecimal, scale_factor: u32) -> StdResult<FPDecimal> {
+    let scale_factor = i32::try_from(scale_factor).map_err(|_| StdError::generic_err(format!("Oracle scale factor {scale_factor} out of range")))?;
+    scale_by_power_of_ten(price, scale_factor)
+}
+
+fn query_pair_price(querier: &InjectiveQuerier, oracle_type: OracleType, base: &str, quote: &str) -> StdResult<FPDecimal> {
+    let response = querier.query_oracle_price(&oracle_type, base, quote)?;
+    match response.price_pair_state {
+        Some(state) => Ok(state.pair_price),
+        None => Err(StdError::not_found(format!("Oracle price for {base}/{quote}"))),
+    }
+}
+
+/// Returns the oracle price of a derivative market expressed in the market's orderbook price units,
+/// i.e. the pair price scaled by the market's `oracle_scale_factor`
+pub fn query_derivative_market_oracle_price(querier: &InjectiveQuerier, market: &DerivativeMarket) -> StdResult<FPDecimal> {
+    let pair_price = query_pair_price(querier, market.oracle_type, &market.oracle_base, &market.oracle_quote)?;
+    scale_oracle_price(pair_price, market.oracle_scale_factor)
+}
+
+/// Returns the oracle price of the `base_symbol`/`quote_symbol` pair expressed in the orderbook price units of a spot market,
+/// i.e. the pair price converted from human units into chain units with the market's base and quote denom decimals,
+/// multiplied by `10^(quote_decimals - base_decimals)`
+pub fn query_spot_market_oracle_price(
+    querier: &InjectiveQuerier,
+    market: &SpotMarket,
+    oracle_type: OracleType,
+    base_symbol: &str,
+    quote_symbol: &str,
+) -> StdResult<FPDecimal> {
+    let pair_price = query_pair_price(querier, oracle_type, base_symbol, quote_symbol)?;
+    SpotMarketDecimals::query(querier, market)?.human_price_to_chain(pair_price)
+}
+
+/// Returns the oracle price of the `base_info.symbol`/`quote_info.symbol` pair expressed in chain units, the way the
+/// exchange module scales `OracleInfo` pairs:
+///
+/// - `symbol` selects the side of the oracle pair, and `oracle_type` the oracle both sides must be read from
+/// - `scale_factor` is the number of decimals of the side's denom, so the pair price is multiplied by
+///   `10^(quote_info.scale_factor - base_info.scale_factor)` exactly once
+///
+/// The denom decimals are not queried, the scale factors take their place. A spot market's `OracleInfo` values thus
+/// yield the same price as `query_spot_market_oracle_price`.
+pub fn query_oracle_price_with_info(querier: &InjectiveQuerier, base_info: &OracleInfo, quote_info: &OracleInfo) -> StdResult<FPDecimal> {
+    if base_info.oracle_type != quote_info.oracle_type {
+        return Err(StdError::generic_err(format!(
+            "Oracle types of {} and {} differ",
+            base_info.symbol, quote_info.symbol
+        )));
+    }
+
+    let pair_price = query_pair_price(querier, base_info.oracle_type, &base_info.symbol, &quote_info.symbol)?;
+    let exponent = i64::from(quote_info.scale_factor) - i64::from(base_info.scale_factor);
+    let exponent = i32::try_from(exponent).map_err(|_| StdError::generic_err(format!("Oracle scale exponent {exponent} out of range")))?;
+    scale_by_power_of_ten(pair_price, exponent)
+}
+
+/// Returns the decimals of the base and quote denoms of a spot market
+pub fn query_market_denom_decimals(querier: &InjectiveQuerier, market: &SpotMarket) -> StdResult<(i32, i32)> {
+    let denoms = vec![market.base_denom.to_owned(), market.quote_denom.to_owned()];
+    let response = querier.query_denom_decimals(&denoms)?;
+
+    let decimals_of = |denom: &str| -> StdResult<i32> {
+        let decimals = response
+            .denom_decimals
+            .iter()
+            .find(|denom_decimals| denom_decimals.denom == denom)
+            .ok_or_else(|| StdError::not_found(format!("Decimals for denom {denom}")))?
+            .decimals;
+        i32::try_from(decimals).map_err(|_| StdError::generic_err(format!("Invalid decimals {decimals} for denom {denom}")))
+    };
+
+    Ok((decimals_of(&market.base_denom)?, decimals_of(&market.quote_denom)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{QuerierWrapper, StdError};
+    use injective_math::FPDecimal;
+
+    use crate::oracle_price::{
+        query_derivative_market_oracle_price, query_oracle_price_with_info, query_spot_market_oracle_price, scale_by_power_of_ten, scale_oracle_price,
+    };
+    use crate::query::DenomDecimals;
+    use crate::{
+        create_denom_decimals_handler, create_oracle_query_handler, mock_dependencies, DerivativeMarket, InjectiveQuerier, MarketId, OracleInfo,
+        OracleType, SpotMarket,
+    };
+
+    fn spot_market() -> SpotMarket {
+        SpotMarket {
+            ticker: "INJ/USDT".to_string(),
+            base_denom: "inj".to_string(),
+            quote_denom: "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+            maker_fee_rate: FPDecimal::zero(),
+            taker_fee_rate: FPDecimal::zero(),
+            relayer_fee_share_rate: FPDecimal::zero(),
+            market_id: MarketId::unchecked("0x01"),
+            status: 1,
+            min_price_tick_size: FPDecimal::zero(),
+            min_quantity_tick_size: FPDecimal::zero(),
+        }
+    }
+
+    fn pair_price_handler(pair_price: &str) -> Option<Box<dyn crate::HandlesOraclePriceQuery>> {
+        create_oracle_query_handler(
+            FPDecimal::from_str(pair_price).unwrap(),
+            FPDecimal::zero(),
+            FPDecimal::zero(),
+            FPDecimal::zero(),
+            FPDecimal::zero(),
+            0,
+            0,
+        )
+    }
+
+    #[test]
+    fn scales_by_power_of_ten() {
+        let price = FPDecimal::from_str("1.5").unwrap();
+        assert_eq!(scale_by_power_of_ten(price, 6).unwrap(), FPDecimal::from(1_500_000u128));
+        assert_eq!(
+            scale_by_power_of_ten(price, -12).unwrap(),
+            FPDecimal::from_str("0.0000000000015").unwrap()
+        );
+        assert_eq!(scale_oracle_price(price, 0).unwrap(), price);
+        assert!(scale_by_power_of_ten(price, 40).is_err());
+        assert!(scale_by_power_of_ten(FPDecimal::MAX, 1).is_err());
+        assert!(scale_by_power_of_ten(FPDecimal::MAX, -1).is_ok());
+    }
+
+    #[test]
+    fn derivative_market_oracle_price_applies_scale_factor() {
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_price_response_handler = pair_price_handler("12.5");
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let market = DerivativeMarket {
+            ticker: "INJ/USDT PERP".to_string(),
+            oracle_base: "INJ".to_string(),
+            oracle_quote: "USDT".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            oracle_scale_factor: 6,
+            quote_denom: "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+            market_id: MarketId::unchecked("0x02"),
+            initial_margin_ratio: FPDecimal::zero(),
+            maintenance_margin_ratio: FPDecimal::zero(),
+            maker_fee_rate: FPDecimal::zero(),
+            taker_fee_rate: FPDecimal::zero(),
+            isPerpetual: true,
+            status: 1,
+            min_price_tick_size: FPDecimal::zero(),
+            min_quantity_tick_size: FPDecimal::zero(),
+        };
+
+        let price = query_derivative_market_oracle_price(&querier, &market).unwrap();
+        assert_eq!(price, FPDecimal::from(12_500_000u128));
+    }
+
+    #[test]
+    fn spot_market_oracle_price_applies_denom_decimals() {
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_price_response_handler = pair_price_handler("12.5");
+        deps.querier.denom_decimals_handler = create_denom_decimals_handler(vec![
+            DenomDecimals {
+                denom: "inj".to_string(),
+                decimals: 18,
+            },
+            DenomDecimals {
+                denom: "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+                decimals: 6,
+            },
+        ]);
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let price = query_spot_market_oracle_price(&querier, &spot_market(), OracleType::PriceFeed, "INJ", "USDT").unwrap();
+        assert_eq!(price, FPDecimal::from_str("0.0000000000125").unwrap());
+    }
+
+    fn inj_usdt_infos() -> (OracleInfo, OracleInfo) {
+        let base_info = OracleInfo {
+            symbol: "INJ".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            scale_factor: 18,
+        };
+        let quote_info = OracleInfo {
+            symbol: "USDT".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            scale_factor: 6,
+        };
+        (base_info, quote_info)
+    }
+
+    #[test]
+    fn oracle_price_with_info_scales_by_decimals_once() {
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_price_response_handler = pair_price_handler("12.5");
+        deps.querier.denom_decimals_handler = create_denom_decimals_handler(vec![
+            DenomDecimals {
+                denom: "inj".to_string(),
+                decimals: 18,
+            },
+            DenomDecimals {
+                denom: "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+                decimals: 6,
+            },
+        ]);
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let (base_info, quote_info) = inj_usdt_infos();
+        let price = query_oracle_price_with_info(&querier, &base_info, &quote_info).unwrap();
+
+        // 12.5 USDT per INJ is 12_500_000 USDT base units per 10^18 INJ base units
+        let expected = FPDecimal::from(12_500_000u128) / FPDecimal::from(10u128.pow(18));
+        assert_eq!(price, expected);
+        assert_eq!(
+            price,
+            query_spot_market_oracle_price(&querier, &spot_market(), OracleType::PriceFeed, "INJ", "USDT").unwrap()
+        );
+    }
+
+    #[test]
+    fn oracle_price_with_info_requires_matching_oracle_types() {
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_price_response_handler = pair_price_handler("12.5");
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let (base_info, mut quote_info) = inj_usdt_infos();
+        quote_info.oracle_type = OracleType::Band;
+        assert!(query_oracle_price_with_info(&querier, &base_info, &quote_info).is_err());
+    }
+
+    #[test]
+    fn spot_market_oracle_price_requires_denom_decimals() {
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_price_response_handler = pair_price_handler("12.5");
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let err = query_spot_market_oracle_price(&querier, &spot_market(), OracleType::PriceFeed, "INJ", "USDT").unwrap_err();
+        assert!(matches!(err, StdError::NotFound { .. }));
+    }
+}
@@ -12,6 +12,10 @@ pub enum OrderType {
     Undefined = 0,
     Buy = 1,
     Sell = 2,
+    StopBuy = 3,
+    StopSell = 4,
+    TakeBuy = 5,
+    TakeSell = 6,
     BuyPo = 7,
     SellPo = 8,
     BuyAtomic = 9,
@@ -34,6 +38,15 @@ pub struct OrderInfo {
     pub quantity: FPDecimal,
 }
 
+/// Outcome of a `MsgBatchUpdateOrders`, cancellation flags and order hashes follow the order of the request
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MsgBatchUpdateOrdersResponse {
+    pub spot_cancel_success: Vec<bool>,
+    pub derivative_cancel_success: Vec<bool>,
+    pub spot_order_hashes: Vec<String>,
+    pub derivative_order_hashes: Vec<String>,
+}
+
 pub trait GenericOrder {
     fn get_order_type(&self) -> &OrderType;
     fn get_order_info(&self) -> &OrderInfo;
@@ -48,7 +61,15 @@ mod tests {
 
     #[test]
     fn order_type_serialization() {
-        let types = vec![OrderType::Undefined, OrderType::Buy, OrderType::SellPo, OrderType::SellAtomic];
-        assert_eq!(serde_json_wasm::to_string(&types).unwrap(), "[0,1,8,10]");
+        let types = vec![
+            OrderType::Undefined,
+            OrderType::Buy,
+            OrderType::StopBuy,
+            OrderType::TakeSell,
+            OrderType::SellPo,
+            OrderType::SellAtomic,
+        ];
+        assert_eq!(serde_json_wasm::to_string(&types).unwrap(), "[0,1,3,6,8,10]");
+        assert_eq!(serde_json_wasm::from_str::<Vec<OrderType>>("[0,1,3,6,8,10]").unwrap(), types);
     }
 }
@@ -0,0 +1,271 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Coin, StdResult, Uint128};
+use injective_math::FPDecimal;
+use injective_protobuf::proto::{exchange, ibc, oracle, tokenfactory};
+use protobuf::SingularPtrField;
+
+use crate::oracle::PriceAttestation;
+use crate::order::{OrderData, OrderInfo, OrderType};
+use crate::{DerivativeOrder, MarketId, SpotOrder, SubaccountId};
+
+/// Converts a crate type into its generated `injective-protobuf` message, with `FPDecimal`s encoded as sdk.Dec strings
+pub trait ToProto<P> {
+    fn to_proto(&self) -> P;
+}
+
+/// Converts a generated `injective-protobuf` message into a crate type, validating ids and decoding sdk.Dec strings
+pub trait FromProto<P>: Sized {
+    fn from_proto(proto: &P) -> StdResult<Self>;
+}
+
+macro_rules! coin_to_proto {
+    ($proto:ty) => {
+        impl ToProto<$proto> for Coin {
+            fn to_proto(&self) -> $proto {
+                let mut proto = <$proto>::new();
+                proto.denom = self.denom.to_owned();
+                proto.amount = self.amount.to_string();
+                proto
+            }
+        }
+    };
+}
+
+coin_to_proto!(exchange::coin::Coin);
+coin_to_proto!(tokenfactory::coin::Coin);
+coin_to_proto!(ibc::coin::Coin);
+
+impl FromProto<exchange::coin::Coin> for Coin {
+    fn from_proto(proto: &exchange::coin::Coin) -> StdResult<Self> {
+        Ok(Coin {
+            denom: proto.denom.to_owned(),
+            amount: Uint128::from_str(&proto.amount)?,
+        })
+    }
+}
+
+impl ToProto<exchange::exchange::OrderType> for OrderType {
+    fn to_proto(&self) -> exchange::exchange::OrderType {
+        use exchange::exchange::OrderType as Proto;
+        match self {
+            OrderType::Undefined => Proto::UNSPECIFIED,
+            OrderType::Buy => Proto::BUY,
+            OrderType::Sell => Proto::SELL,
+            OrderType::StopBuy => Proto::STOP_BUY,
+            OrderType::StopSell => Proto::STOP_SELL,
+            OrderType::TakeBuy => Proto::TAKE_BUY,
+            OrderType::TakeSell => Proto::TAKE_SELL,
+            OrderType::BuyPo => Proto::BUY_PO,
+            OrderType::SellPo => Proto::SELL_PO,
+            OrderType::BuyAtomic => Proto::BUY_ATOMIC,
+            OrderType::SellAtomic => Proto::SELL_ATOMIC,
+        }
+    }
+}
+
+impl FromProto<exchange::exchange::OrderType> for OrderType {
+    fn from_proto(proto: &exchange::exchange::OrderType) -> StdResult<Self> {
+        use exchange::exchange::OrderType as Proto;
+        match proto {
+            Proto::UNSPECIFIED => Ok(OrderType::Undefined),
+            Proto::BUY => Ok(OrderType::Buy),
+            Proto::SELL => Ok(OrderType::Sell),
+            Proto::STOP_BUY => Ok(OrderType::StopBuy),
+            Proto::STOP_SELL => Ok(OrderType::StopSell),
+            Proto::TAKE_BUY => Ok(OrderType::TakeBuy),
+            Proto::TAKE_SELL => Ok(OrderType::TakeSell),
+            Proto::BUY_PO => Ok(OrderType::BuyPo),
+            Proto::SELL_PO => Ok(OrderType::SellPo),
+            Proto::BUY_ATOMIC => Ok(OrderType::BuyAtomic),
+            Proto::SELL_ATOMIC => Ok(OrderType::SellAtomic),
+        }
+    }
+}
+
+impl ToProto<exchange::exchange::OrderInfo> for OrderInfo {
+    fn to_proto(&self) -> exchange::exchange::OrderInfo {
+        let mut proto = exchange::exchange::OrderInfo::new();
+        proto.subaccount_id = self.subaccount_id.as_str().to_string();
+        proto.fee_recipient = self.fee_recipient.as_ref().map(|addr| addr.to_string()).unwrap_or_default();
+        proto.price = self.price.to_sdk_dec_str();
+        proto.quantity = self.quantity.to_sdk_dec_str();
+        proto
+    }
+}
+
+impl FromProto<exchange::exchange::OrderInfo> for OrderInfo {
+    fn from_proto(proto: &exchange::exchange::OrderInfo) -> StdResult<Self> {
+        Ok(OrderInfo {
+            subaccount_id: SubaccountId::new(&proto.subaccount_id)?,
+            fee_recipient: (!proto.fee_recipient.is_empty()).then(|| Addr::unchecked(&proto.fee_recipient)),
+            price: FPDecimal::from_sdk_dec_str(&proto.price)?,
+            quantity: FPDecimal::from_sdk_dec_str(&proto.quantity)?,
+        })
+    }
+}
+
+impl ToProto<exchange::exchange::SpotOrder> for SpotOrder {
+    fn to_proto(&self) -> exchange::exchange::SpotOrder {
+        let mut proto = exchange::exchange::SpotOrder::new();
+        proto.market_id = self.market_id.as_str().to_string();
+        proto.order_info = SingularPtrField::some(self.order_info.to_proto());
+        proto.order_type = self.order_type.to_proto();
+        proto.trigger_price = trigger_price_to_proto(self.trigger_price);
+        proto
+    }
+}
+
+impl FromProto<exchange::exchange::SpotOrder> for SpotOrder {
+    fn from_proto(proto: &exchange::exchange::SpotOrder) -> StdResult<Self> {
+        Ok(SpotOrder {
+            market_id: MarketId::new(&proto.market_id)?,
+            order_info: OrderInfo::from_proto(proto.get_order_info())?,
+            order_type: OrderType::from_proto(&proto.order_type)?,
+            trigger_price: trigger_price_from_proto(&proto.trigger_price)?,
+        })
+    }
+}
+
+impl ToProto<exchange::exchange::DerivativeOrder> for DerivativeOrder {
+    fn to_proto(&self) -> exchange::exchange::DerivativeOrder {
+        let mut proto = exchange::exchange::DerivativeOrder::new();
+        proto.market_id = self.market_id.as_str().to_string();
+        proto.order_info = SingularPtrField::some(self.order_info.to_proto());
+        proto.order_type = self.order_type.to_proto();
+        proto.margin = self.margin.to_sdk_dec_str();
+        proto.trigger_price = trigger_price_to_proto(self.trigger_price);
+        proto
+    }
+}
+
+impl FromProto<exchange::exchange::DerivativeOrder> for DerivativeOrder {
+    fn from_proto(proto: &exchange::exchange::DerivativeOrder) -> StdResult<Self> {
+        Ok(DerivativeOrder {
+            market_id: MarketId::new(&proto.market_id)?,
+            order_info: OrderInfo::from_proto(proto.get_order_info())?,
+            order_type: OrderType::from_proto(&proto.order_type)?,
+            margin: FPDecimal::from_sdk_dec_str(&proto.margin)?,
+            trigger_price: trigger_price_from_proto(&proto.trigger_price)?,
+        })
+    }
+}
+
+impl ToProto<exchange::tx::OrderData> for OrderData {
+    fn to_proto(&self) -> exchange::tx::OrderData {
+        let mut proto = exchange::tx::OrderData::new();
+        proto.market_id = self.market_id.as_str().to_string();
+        proto.subaccount_id = self.subaccount_id.as_str().to_string();
+        proto.order_hash = self.order_hash.to_owned();
+        proto
+    }
+}
+
+impl ToProto<oracle::oracle::PriceAttestation> for PriceAttestation {
+    fn to_proto(&self) -> oracle::oracle::PriceAttestation {
+        let mut proto = oracle::oracle::PriceAttestation::new();
+        proto.price_id = self.price_id.to_string();
+        proto.price = self.price;
+        proto.conf = self.conf;
+        proto.expo = self.expo;
+        proto.ema_price = self.ema_price;
+        proto.ema_conf = self.ema_conf;
+        // Pyth attestations carry a single exponent for the price and its EMA
+        proto.ema_expo = self.expo;
+        proto.publish_time = self.publish_time;
+        proto
+    }
+}
+
+// the chain leaves trigger prices of regular orders empty
+fn trigger_price_to_proto(trigger_price: Option<FPDecimal>) -> String {
+    trigger_price.map(|price| price.to_sdk_dec_str()).unwrap_or_default()
+}
+
+fn trigger_price_from_proto(trigger_price: &str) -> StdResult<Option<FPDecimal>> {
+    if trigger_price.is_empty() {
+        Ok(None)
+    } else {
+        FPDecimal::from_sdk_dec_str(trigger_price).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Addr, Coin};
+    use injective_math::FPDecimal;
+    use injective_protobuf::proto::exchange;
+
+    use crate::proto_conversion::{FromProto, ToProto};
+    use crate::{DerivativeOrder, MarketId, OrderType, SpotOrder, SubaccountId};
+
+    const MARKET_ID: &str = "0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b";
+    const SUBACCOUNT_ID: &str = "0xb5e09b93aceb70c1b47cb1e9e5a2d3d4a52d9d20000000000000000000000001";
+
+    #[test]
+    fn spot_order_round_trip() {
+        let order = SpotOrder::new(
+            FPDecimal::from_str("20.25").unwrap(),
+            FPDecimal::from_str("1.5").unwrap(),
+            OrderType::SellPo,
+            &MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            Some(Addr::unchecked("inj1feerecipient")),
+        );
+
+        let proto: exchange::exchange::SpotOrder = order.to_proto();
+        assert_eq!(proto.get_order_info().price, "20250000000000000000");
+        assert_eq!(proto.order_type, exchange::exchange::OrderType::SELL_PO);
+        assert_eq!(SpotOrder::from_proto(&proto).unwrap(), order);
+    }
+
+    #[test]
+    fn derivative_order_round_trip_keeps_trigger_price() {
+        let mut order = DerivativeOrder::new(
+            FPDecimal::from_str("10").unwrap(),
+            FPDecimal::from_str("2").unwrap(),
+            FPDecimal::from_str("5").unwrap(),
+            OrderType::Buy,
+            MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+        );
+        order.trigger_price = Some(FPDecimal::from_str("9.5").unwrap());
+
+        let proto: exchange::exchange::DerivativeOrder = order.to_proto();
+        assert_eq!(proto.margin, "5000000000000000000");
+        assert_eq!(proto.trigger_price, "9500000000000000000");
+        assert_eq!(DerivativeOrder::from_proto(&proto).unwrap(), order);
+    }
+
+    #[test]
+    fn coin_round_trip() {
+        let coin = Coin::new(1_000, "inj");
+        let proto: exchange::coin::Coin = coin.to_proto();
+        assert_eq!(Coin::from_proto(&proto).unwrap(), coin);
+    }
+
+    #[test]
+    fn order_type_round_trip() {
+        let order_types = [
+            OrderType::Undefined,
+            OrderType::Buy,
+            OrderType::Sell,
+            OrderType::StopBuy,
+            OrderType::StopSell,
+            OrderType::TakeBuy,
+            OrderType::TakeSell,
+            OrderType::BuyPo,
+            OrderType::SellPo,
+            OrderType::BuyAtomic,
+            OrderType::SellAtomic,
+        ];
+        for order_type in order_types {
+            let proto: exchange::exchange::OrderType = order_type.to_proto();
+            assert_eq!(proto as i32, order_type.clone() as i32);
+            assert_eq!(OrderType::from_proto(&proto).unwrap(), order_type);
+        }
+    }
+}
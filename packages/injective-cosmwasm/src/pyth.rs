@@ -0,0 +1,320 @@
+use cosmwasm_std::{StdError, Timestamp};
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::oracle::{PriceAttestation, PythStatus};
+use crate::query::PythPriceState;
+use crate::InjectiveQuerier;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PythPriceError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Pyth price {price_id} not found")]
+    NotFound { price_id: String },
+
+    #[error("Pyth price {price_id} is not trading")]
+    NotTrading { price_id: String },
+
+    #[error("Invalid Pyth price {price}: price must be positive")]
+    NonPositivePrice { price: FPDecimal },
+
+    #[error("Invalid Pyth exponent {expo}")]
+    InvalidExponent { expo: i32 },
+
+    #[error("Pyth value {value} with exponent {expo} overflows")]
+    ValueOverflow { value: i128, expo: i32 },
+
+    #[error("Stale Pyth price: published at {publish_time}, block time {block_time}, max age {max_age}s")]
+    StalePrice { publish_time: i64, block_time: i64, max_age: u64 },
+
+    #[error("Pyth price published at {publish_time}, after block time {block_time}")]
+    FuturePrice { publish_time: i64, block_time: i64 },
+
+    #[error("Pyth confidence {conf} exceeds {max_conf_ratio} of price {price}")]
+    ConfidenceTooWide {
+        price: FPDecimal,
+        conf: FPDecimal,
+        max_conf_ratio: FPDecimal,
+    },
+
+    #[error("Pyth price {price} deviates from EMA price {ema_price} by more than {max_ema_deviation}")]
+    EmaDeviationTooLarge {
+        price: FPDecimal,
+        ema_price: FPDecimal,
+        max_ema_deviation: FPDecimal,
+    },
+}
+
+impl From<PythPriceError> for StdError {
+    fn from(err: PythPriceError) -> Self {
+        match err {
+            PythPriceError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// A Pyth price that passed every check of a [`PythPriceGuard`], with the exponent already applied
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ValidatedPythPrice {
+    pub price_id: String,
+    pub price: FPDecimal,
+    pub conf: FPDecimal,
+    pub ema_price: FPDecimal,
+    pub publish_time: i64,
+}
+
+/// Thresholds a Pyth price has to satisfy before it can be used
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PythPriceGuard {
+    /// Maximum age of the price relative to the block time, in seconds
+    pub max_age: u64,
+    /// Maximum confidence interval as a fraction of the price, e.g. 0.01 for 1%
+    pub max_conf_ratio: FPDecimal,
+    /// Maximum relative deviation of the price from the EMA price, e.g. 0.05 for 5%
+    pub max_ema_deviation: FPDecimal,
+}
+
+impl PythPriceGuard {
+    pub fn new(max_age: u64, max_conf_ratio: FPDecimal, max_ema_deviation: FPDecimal) -> Self {
+        PythPriceGuard {
+            max_age,
+            max_conf_ratio,
+            max_ema_deviation,
+        }
+    }
+
+    /// Queries the Pyth price state stored by the oracle module and validates it against `block_time`
+    pub fn query_price(&self, querier: &InjectiveQuerier, price_id: &str, block_time: Timestamp) -> Result<ValidatedPythPrice, PythPriceError> {
+        let response = querier.query_pyth_price(price_id)?;
+        match response.pyth_price_state {
+            Some(state) => self.validate_state(&state, block_time),
+            None => Err(PythPriceError::NotFound {
+                price_id: price_id.to_string(),
+            }),
+        }
+    }
+
+    /// Validates a price state returned by the oracle module. The module applies the exponent when prices are relayed,
+    /// so the values are used as they are.
+    pub fn validate_state(&self, state: &PythPriceState, block_time: Timestamp) -> Result<ValidatedPythPrice, PythPriceError> {
+        self.validate(
+            ValidatedPythPrice {
+                price_id: state.price_id.to_owned(),
+                price: state.price_state.price,
+                conf: state.conf,
+                ema_price: state.ema_price,
+                publish_time: state.publish_time,
+            },
+            block_time,
+        )
+    }
+
+    /// Validates a raw Pyth attestation, scaling its integer price, confidence and EMA price by `10^expo`
+    pub fn validate_attestation(&self, attestation: &PriceAttestation, block_time: Timestamp) -> Result<ValidatedPythPrice, PythPriceError> {
+        if attestation.status != PythStatus::Trading {
+            return Err(PythPriceError::NotTrading {
+                price_id: attestation.price_id.to_hex(),
+            });
+        }
+
+        self.validate(
+            ValidatedPythPrice {
+                price_id: attestation.price_id.to_hex(),
+                price: scale_pyth_value(attestation.price as i128, attestation.expo)?,
+                conf: scale_pyth_value(attestation.conf as i128, attestation.expo)?,
+                ema_price: scale_pyth_value(attestation.ema_price as i128, attestation.expo)?,
+                publish_time: attestation.publish_time,
+            },
+            block_time,
+        )
+    }
+
+    fn validate(&self, price: ValidatedPythPrice, block_time: Timestamp) -> Result<ValidatedPythPrice, PythPriceError> {
+        if price.price.is_negative() || price.price.is_zero() {
+            return Err(PythPriceError::NonPositivePrice { price: price.price });
+        }
+
+        let block_time = block_time.seconds() as i64;
+        let age = block_time.saturating_sub(price.publish_time);
+        if age < 0 {
+            return Err(PythPriceError::FuturePrice {
+                publish_time: price.publish_time,
+                block_time,
+            });
+        }
+        if age as u64 > self.max_age {
+            return Err(PythPriceError::StalePrice {
+                publish_time: price.publish_time,
+                block_time,
+                max_age: self.max_age,
+            });
+        }
+
+        if price.conf > price.price * self.max_conf_ratio {
+            return Err(PythPriceError::ConfidenceTooWide {
+                price: price.price,
+                conf: price.conf,
+                max_conf_ratio: self.max_conf_ratio,
+            });
+        }
+
+        if price.ema_price.is_negative() || price.ema_price.is_zero() {
+            return Err(PythPriceError::NonPositivePrice { price: price.ema_price });
+        }
+        let deviation = (price.price - price.ema_price).abs() / price.ema_price;
+        if deviation > self.max_ema_deviation {
+            return Err(PythPriceError::EmaDeviationTooLarge {
+                price: price.price,
+                ema_price: price.ema_price,
+                max_ema_deviation: self.max_ema_deviation,
+            });
+        }
+
+        Ok(price)
+    }
+}
+
+/// Converts a Pyth integer value into an FPDecimal, i.e. `value * 10^expo`
+pub fn scale_pyth_value(value: i128, expo: i32) -> Result<FPDecimal, PythPriceError> {
+    let scale = 10u128.checked_pow(expo.unsigned_abs()).ok_or(PythPriceError::InvalidExponent { expo })?;
+
+    if expo < 0 {
+        Ok(FPDecimal::from(value) / FPDecimal::from(scale))
+    } else {
+        FPDecimal::from(value)
+            .checked_mul(FPDecimal::from(scale))
+            .map_err(|_| PythPriceError::ValueOverflow { value, expo })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{QuerierWrapper, Timestamp};
+    use injective_math::FPDecimal;
+
+    use crate::oracle::{PriceAttestation, PythStatus};
+    use crate::pyth::{scale_pyth_value, PythPriceError, PythPriceGuard};
+    use crate::query::{PriceState, PythPriceState};
+    use crate::{create_pyth_price_handler, mock_dependencies, Hash, InjectiveQuerier};
+
+    fn guard() -> PythPriceGuard {
+        PythPriceGuard::new(60, FPDecimal::from_str("0.01").unwrap(), FPDecimal::from_str("0.05").unwrap())
+    }
+
+    fn pyth_price_state(price: &str, conf: &str, ema_price: &str, publish_time: i64) -> PythPriceState {
+        PythPriceState {
+            price_id: "0x01".to_string(),
+            ema_price: FPDecimal::from_str(ema_price).unwrap(),
+            ema_conf: FPDecimal::zero(),
+            conf: FPDecimal::from_str(conf).unwrap(),
+            publish_time,
+            price_state: PriceState {
+                price: FPDecimal::from_str(price).unwrap(),
+                cumulative_price: FPDecimal::zero(),
+                timestamp: publish_time,
+            },
+        }
+    }
+
+    #[test]
+    fn scales_pyth_values_by_exponent() {
+        assert_eq!(scale_pyth_value(123456789, -8).unwrap(), FPDecimal::from_str("1.23456789").unwrap());
+        assert_eq!(scale_pyth_value(-15, 2).unwrap(), FPDecimal::from(-1500i128));
+        assert_eq!(scale_pyth_value(1, 39).unwrap_err(), PythPriceError::InvalidExponent { expo: 39 });
+        assert_eq!(
+            scale_pyth_value(i128::MAX, 30).unwrap_err(),
+            PythPriceError::ValueOverflow { value: i128::MAX, expo: 30 }
+        );
+    }
+
+    #[test]
+    fn validates_fresh_tight_price() {
+        let state = pyth_price_state("100", "0.5", "98", 1000);
+        let price = guard().validate_state(&state, Timestamp::from_seconds(1060)).unwrap();
+        assert_eq!(price.price, FPDecimal::from(100u128));
+        assert_eq!(price.publish_time, 1000);
+    }
+
+    #[test]
+    fn rejects_invalid_prices_with_distinct_errors() {
+        let guard = guard();
+        let block_time = Timestamp::from_seconds(1060);
+
+        let stale = guard.validate_state(&pyth_price_state("100", "0.5", "100", 999), block_time);
+        assert!(matches!(stale, Err(PythPriceError::StalePrice { publish_time: 999, .. })));
+
+        let future = guard.validate_state(&pyth_price_state("100", "0.5", "100", 1061), block_time);
+        assert_eq!(
+            future.unwrap_err(),
+            PythPriceError::FuturePrice {
+                publish_time: 1061,
+                block_time: 1060
+            }
+        );
+
+        let wide = guard.validate_state(&pyth_price_state("100", "1.01", "100", 1050), block_time);
+        assert!(matches!(wide, Err(PythPriceError::ConfidenceTooWide { .. })));
+
+        let deviating = guard.validate_state(&pyth_price_state("100", "0.5", "90", 1050), block_time);
+        assert!(matches!(deviating, Err(PythPriceError::EmaDeviationTooLarge { .. })));
+
+        let zero = guard.validate_state(&pyth_price_state("0", "0", "100", 1050), block_time);
+        assert!(matches!(zero, Err(PythPriceError::NonPositivePrice { .. })));
+    }
+
+    #[test]
+    fn validates_attestation() {
+        let mut attestation = PriceAttestation {
+            product_id: "product".to_string(),
+            price_id: Hash::new([1u8; 32]),
+            price: 1_000_000_000,
+            conf: 5_000_000,
+            expo: -8,
+            ema_price: 1_010_000_000,
+            ema_conf: 5_000_000,
+            status: PythStatus::Trading,
+            num_publishers: 10,
+            max_num_publishers: 10,
+            attestation_time: 1000,
+            publish_time: 1000,
+        };
+
+        let price = guard().validate_attestation(&attestation, Timestamp::from_seconds(1010)).unwrap();
+        assert_eq!(price.price, FPDecimal::from(10u128));
+        assert_eq!(price.conf, FPDecimal::from_str("0.05").unwrap());
+        assert_eq!(price.ema_price, FPDecimal::from_str("10.1").unwrap());
+
+        attestation.status = PythStatus::Halted;
+        let err = guard().validate_attestation(&attestation, Timestamp::from_seconds(1010)).unwrap_err();
+        assert!(matches!(err, PythPriceError::NotTrading { .. }));
+    }
+
+    #[test]
+    fn queries_validated_price() {
+        let mut deps = mock_dependencies();
+        deps.querier.pyth_price_response_handler = create_pyth_price_handler(Some(pyth_price_state("100", "0.5", "100", 1000)));
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+        let price = guard().query_price(&querier, "0x01", Timestamp::from_seconds(1000)).unwrap();
+        assert_eq!(price.price, FPDecimal::from(100u128));
+
+        let mut deps = mock_dependencies();
+        deps.querier.pyth_price_response_handler = create_pyth_price_handler(None);
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+        let err = guard().query_price(&querier, "0x01", Timestamp::from_seconds(1000)).unwrap_err();
+        assert_eq!(
+            err,
+            PythPriceError::NotFound {
+                price_id: "0x01".to_string()
+            }
+        );
+    }
+}
@@ -1,14 +1,18 @@
 use cosmwasm_std::{QuerierWrapper, StdResult};
+#[cfg(feature = "stargate")]
+use cosmwasm_std::{QueryRequest, StdError};
 
 use injective_math::FPDecimal;
 
+#[cfg(feature = "stargate")]
+use crate::ibc::{encode_denom_trace_request, DenomTrace, QueryDenomTraceResponse, DENOM_TRACE_QUERY_PATH};
 use crate::oracle::{OracleHistoryOptions, OracleInfo};
 use crate::query::{
-    DerivativeMarketResponse, InjectiveQuery, InjectiveQueryWrapper, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OraclePriceResponse,
-    OracleVolatilityResponse, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PythPriceResponse, QueryAggregateVolumeResponse,
-    QueryContractRegistrationInfoResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse, SpotMarketResponse, SubaccountDepositResponse,
-    SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TokenFactoryCreateDenomFeeResponse,
-    TokenFactoryDenomSupplyResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
+    DerivativeMarketResponse, InjectiveQuery, InjectiveQueryWrapper, MarketMidPriceAndTOBResponse, MarketVolatilityResponse,
+    OraclePriceHistoryResponse, OraclePriceResponse, OracleVolatilityResponse, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse,
+    PythPriceResponse, QueryAggregateVolumeResponse, QueryContractRegistrationInfoResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse,
+    SpotMarketResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse,
+    TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
 };
 use crate::route::InjectiveRoute;
 use crate::volatility::TradeHistoryOptions;
@@ -359,6 +363,31 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    /// Queries the raw oracle price history of the pair over the last `max_age` seconds.
+    /// The oracle module serves it through the `OracleVolatility` query, which records prices without quantities.
+    pub fn query_oracle_price_history(
+        &self,
+        base_info: &'a Option<OracleInfo>,
+        quote_info: &'a Option<OracleInfo>,
+        max_age: u64,
+    ) -> StdResult<OraclePriceHistoryResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Oracle,
+            query_data: InjectiveQuery::OracleVolatility {
+                base_info: base_info.clone(),
+                quote_info: quote_info.clone(),
+                oracle_history_options: Some(OracleHistoryOptions {
+                    max_age,
+                    include_raw_history: true,
+                    include_metadata: true,
+                }),
+            },
+        };
+
+        let res: OraclePriceHistoryResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_oracle_price(&self, oracle_type: &'a OracleType, base: &str, quote: &str) -> StdResult<OraclePriceResponse> {
         let request = InjectiveQueryWrapper {
             route: InjectiveRoute::Oracle,
@@ -419,4 +448,17 @@ impl<'a> InjectiveQuerier<'a> {
         let res: QueryContractRegistrationInfoResponse = self.querier.query(&request.into())?;
         Ok(res)
     }
+
+    /// Resolves an `ibc/{hash}` voucher denom to the path and base denom it originates from
+    #[cfg(feature = "stargate")]
+    pub fn query_ibc_denom_trace<T: Into<String> + Clone>(&self, ibc_denom: &'a T) -> StdResult<DenomTrace> {
+        let ibc_denom: String = ibc_denom.clone().into();
+        let request: QueryRequest<InjectiveQueryWrapper> = QueryRequest::Stargate {
+            path: DENOM_TRACE_QUERY_PATH.to_string(),
+            data: encode_denom_trace_request(&ibc_denom)?,
+        };
+
+        let res: QueryDenomTraceResponse = self.querier.query(&request)?;
+        res.denom_trace.ok_or_else(|| StdError::not_found(format!("Denom trace of {ibc_denom}")))
+    }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Coin, CustomQuery, Uint128};
+use cosmwasm_std::{Coin, CustomQuery, StdError, StdResult, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -13,7 +13,7 @@ use crate::{
     oracle::{OracleHistoryOptions, OracleInfo},
     route::InjectiveRoute,
     spot::TrimmedSpotLimitOrder,
-    volatility::{MetadataStatistics, TradeHistoryOptions, TradeRecord},
+    volatility::{MetadataStatistics, PriceRecord, TradeHistoryOptions, TradeRecord},
     OracleType, Position, SpotMarket,
 };
 use crate::{MarketId, SubaccountId};
@@ -190,6 +190,14 @@ pub struct OracleVolatilityResponse {
     pub raw_history: Option<Vec<TradeRecord>>,
 }
 
+/// Oracle price history, as returned by the `OracleVolatility` query when raw history is requested
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct OraclePriceHistoryResponse {
+    pub history_metadata: Option<MetadataStatistics>,
+    #[serde(default)]
+    pub raw_history: Option<Vec<PriceRecord>>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct OraclePriceResponse {
     pub price_pair_state: Option<PricePairState>,
@@ -218,6 +226,38 @@ pub struct PricePairState {
     pub quote_timestamp: i64,
 }
 
+impl PricePairState {
+    /// Time-weighted average base price between an `earlier` snapshot of the pair and this one
+    pub fn base_twap_since(&self, earlier: &PricePairState) -> StdResult<FPDecimal> {
+        twap_from_cumulative_prices(
+            earlier.base_cumulative_price,
+            earlier.base_timestamp,
+            self.base_cumulative_price,
+            self.base_timestamp,
+        )
+    }
+
+    /// Time-weighted average quote price between an `earlier` snapshot of the pair and this one
+    pub fn quote_twap_since(&self, earlier: &PricePairState) -> StdResult<FPDecimal> {
+        twap_from_cumulative_prices(
+            earlier.quote_cumulative_price,
+            earlier.quote_timestamp,
+            self.quote_cumulative_price,
+            self.quote_timestamp,
+        )
+    }
+
+    /// Time-weighted average pair price (base TWAP over quote TWAP) between an `earlier` snapshot of the pair and this one
+    pub fn pair_twap_since(&self, earlier: &PricePairState) -> StdResult<FPDecimal> {
+        let base_twap = self.base_twap_since(earlier)?;
+        let quote_twap = self.quote_twap_since(earlier)?;
+        if quote_twap.is_zero() {
+            return Err(StdError::generic_err("Quote TWAP is zero"));
+        }
+        Ok(base_twap / quote_twap)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PriceState {
     #[serde(default)]
@@ -228,6 +268,38 @@ pub struct PriceState {
     pub timestamp: i64,
 }
 
+impl PriceState {
+    /// Time-weighted average price between an `earlier` snapshot of the price state and this one
+    pub fn twap_since(&self, earlier: &PriceState) -> StdResult<FPDecimal> {
+        twap_from_cumulative_prices(earlier.cumulative_price, earlier.timestamp, self.cumulative_price, self.timestamp)
+    }
+}
+
+/// Computes a TWAP from two cumulative price snapshots. The oracle module accumulates `price * seconds` into the
+/// cumulative price, so the average over the window is the cumulative difference divided by the elapsed time.
+pub fn twap_from_cumulative_prices(
+    start_cumulative_price: FPDecimal,
+    start_timestamp: i64,
+    end_cumulative_price: FPDecimal,
+    end_timestamp: i64,
+) -> StdResult<FPDecimal> {
+    if end_timestamp <= start_timestamp {
+        return Err(StdError::generic_err(format!(
+            "Invalid TWAP window: end timestamp {end_timestamp} must be after start timestamp {start_timestamp}"
+        )));
+    }
+    if end_cumulative_price < start_cumulative_price {
+        return Err(StdError::generic_err("Cumulative price decreased within TWAP window"));
+    }
+
+    let elapsed = end_timestamp
+        .checked_sub(start_timestamp)
+        .ok_or_else(|| StdError::generic_err(format!("TWAP window from {start_timestamp} to {end_timestamp} is out of range")))?;
+    end_cumulative_price
+        .checked_sub(start_cumulative_price)?
+        .checked_div(FPDecimal::from(elapsed as i128))
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PythPriceState {
     #[serde(default)]
@@ -319,3 +391,101 @@ pub struct QueryDenomDecimalResponse {
 pub struct QueryDenomDecimalsResponse {
     pub denom_decimals: Vec<DenomDecimals>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{QuerierWrapper, StdError};
+    use injective_math::FPDecimal;
+
+    use crate::query::{twap_from_cumulative_prices, PricePairState, PriceState};
+    use crate::{create_oracle_volatility_handler, mock_dependencies, InjectiveQuerier, OracleInfo, OracleType, PriceRecord, TradeRecord};
+
+    fn price_pair_state(base_cumulative_price: &str, quote_cumulative_price: &str, timestamp: i64) -> PricePairState {
+        PricePairState {
+            pair_price: FPDecimal::zero(),
+            base_price: FPDecimal::zero(),
+            quote_price: FPDecimal::zero(),
+            base_cumulative_price: FPDecimal::from_str(base_cumulative_price).unwrap(),
+            quote_cumulative_price: FPDecimal::from_str(quote_cumulative_price).unwrap(),
+            base_timestamp: timestamp,
+            quote_timestamp: timestamp,
+        }
+    }
+
+    #[test]
+    fn twap_from_price_state_snapshots() {
+        let earlier = PriceState {
+            price: FPDecimal::from(10u128),
+            cumulative_price: FPDecimal::from(1000u128),
+            timestamp: 100,
+        };
+        let later = PriceState {
+            price: FPDecimal::from(14u128),
+            cumulative_price: FPDecimal::from(2200u128),
+            timestamp: 200,
+        };
+
+        assert_eq!(later.twap_since(&earlier).unwrap(), FPDecimal::from(12u128));
+        assert_eq!(
+            earlier.twap_since(&later).unwrap_err(),
+            StdError::generic_err("Invalid TWAP window: end timestamp 100 must be after start timestamp 200")
+        );
+    }
+
+    #[test]
+    fn twap_from_price_pair_state_snapshots() {
+        let earlier = price_pair_state("1000", "100", 100);
+        let later = price_pair_state("3000", "300", 200);
+
+        assert_eq!(later.base_twap_since(&earlier).unwrap(), FPDecimal::from(20u128));
+        assert_eq!(later.quote_twap_since(&earlier).unwrap(), FPDecimal::from(2u128));
+        assert_eq!(later.pair_twap_since(&earlier).unwrap(), FPDecimal::from(10u128));
+
+        let flat_quote = price_pair_state("3000", "100", 200);
+        assert_eq!(
+            flat_quote.pair_twap_since(&earlier).unwrap_err(),
+            StdError::generic_err("Quote TWAP is zero")
+        );
+    }
+
+    #[test]
+    fn twap_rejects_decreasing_cumulative_price() {
+        let err = twap_from_cumulative_prices(FPDecimal::from(10u128), 0, FPDecimal::from(5u128), 10).unwrap_err();
+        assert_eq!(err, StdError::generic_err("Cumulative price decreased within TWAP window"));
+    }
+
+    #[test]
+    fn twap_rejects_out_of_range_windows() {
+        let err = twap_from_cumulative_prices(FPDecimal::zero(), i64::MIN, FPDecimal::ONE, 1).unwrap_err();
+        assert_eq!(err, StdError::generic_err(format!("TWAP window from {} to 1 is out of range", i64::MIN)));
+    }
+
+    #[test]
+    fn query_oracle_price_history() {
+        let mut deps = mock_dependencies();
+        let raw_history = vec![TradeRecord {
+            timestamp: 100,
+            price: FPDecimal::from(5u128),
+            quantity: FPDecimal::zero(),
+        }];
+        deps.querier.oracle_volatility_response_handler = create_oracle_volatility_handler(None, None, Some(raw_history));
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let oracle_info = Some(OracleInfo {
+            symbol: "INJ".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            scale_factor: 0,
+        });
+        let response = querier.query_oracle_price_history(&oracle_info, &None, 3600).unwrap();
+        assert_eq!(
+            response.raw_history,
+            Some(vec![PriceRecord {
+                timestamp: 100,
+                price: FPDecimal::from(5u128),
+            }])
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use cosmwasm_std::{Reply, StdError, SubMsgResult};
+use injective_math::FPDecimal;
+use injective_protobuf::proto::exchange::{exchange, tx};
+use injective_protobuf::proto::tokenfactory::tx as tokenfactory_tx;
+use protobuf::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::derivative::{DerivativeMarketOrderResults, MsgCreateDerivativeMarketOrderResponse, PositionDelta};
+use crate::order::MsgBatchUpdateOrdersResponse;
+use crate::spot::{MsgCreateSpotLimitOrderResponse, MsgCreateSpotMarketOrderResponse, SpotMarketOrderResults};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReplyError {
+    #[error("Failure response from sub-message {id}: {err}")]
+    SubMsgFailure { id: u64, err: String },
+
+    #[error("Missing reply data from sub-message {id}")]
+    MissingData { id: u64 },
+
+    #[error("Invalid reply from sub-message {id}: {err}")]
+    Decode { id: u64, err: String },
+
+    #[error("Missing {field} in reply from sub-message {id}")]
+    MissingField { id: u64, field: String },
+
+    #[error("Invalid decimal {value} for {field} in reply from sub-message {id}")]
+    InvalidDecimal { id: u64, field: String, value: String },
+}
+
+impl From<ReplyError> for StdError {
+    fn from(err: ReplyError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MsgCreateDenomResponse {
+    pub new_token_denom: String,
+}
+
+pub fn parse_create_spot_market_order_reply(reply: &Reply) -> Result<MsgCreateSpotMarketOrderResponse, ReplyError> {
+    let id = reply.id;
+    let response: tx::MsgCreateSpotMarketOrderResponse = decode_reply(reply)?;
+    let results = response.results.into_option().ok_or_else(|| missing_field(id, "results"))?;
+
+    Ok(MsgCreateSpotMarketOrderResponse {
+        order_hash: response.order_hash,
+        results: SpotMarketOrderResults {
+            quantity: parse_sdk_dec(id, "results.quantity", &results.quantity)?,
+            price: parse_sdk_dec(id, "results.price", &results.price)?,
+            fee: parse_sdk_dec(id, "results.fee", &results.fee)?,
+        },
+    })
+}
+
+pub fn parse_create_derivative_market_order_reply(reply: &Reply) -> Result<MsgCreateDerivativeMarketOrderResponse, ReplyError> {
+    let id = reply.id;
+    let response: tx::MsgCreateDerivativeMarketOrderResponse = decode_reply(reply)?;
+    let results = response.results.into_option().ok_or_else(|| missing_field(id, "results"))?;
+    let position_delta = results
+        .position_delta
+        .into_option()
+        .ok_or_else(|| missing_field(id, "results.position_delta"))?;
+
+    Ok(MsgCreateDerivativeMarketOrderResponse {
+        order_hash: response.order_hash,
+        results: DerivativeMarketOrderResults {
+            quantity: parse_sdk_dec(id, "results.quantity", &results.quantity)?,
+            price: parse_sdk_dec(id, "results.price", &results.price)?,
+            fee: parse_sdk_dec(id, "results.fee", &results.fee)?,
+            position_delta: parse_position_delta(id, &position_delta)?,
+            payout: parse_sdk_dec(id, "results.payout", &results.payout)?,
+        },
+    })
+}
+
+pub fn parse_create_spot_limit_order_reply(reply: &Reply) -> Result<MsgCreateSpotLimitOrderResponse, ReplyError> {
+    let response: tx::MsgCreateSpotLimitOrderResponse = decode_reply(reply)?;
+    Ok(MsgCreateSpotLimitOrderResponse {
+        order_hash: response.order_hash,
+    })
+}
+
+pub fn parse_batch_update_orders_reply(reply: &Reply) -> Result<MsgBatchUpdateOrdersResponse, ReplyError> {
+    let response: tx::MsgBatchUpdateOrdersResponse = decode_reply(reply)?;
+    Ok(MsgBatchUpdateOrdersResponse {
+        spot_cancel_success: response.spot_cancel_success,
+        derivative_cancel_success: response.derivative_cancel_success,
+        spot_order_hashes: response.spot_order_hashes.into_vec(),
+        derivative_order_hashes: response.derivative_order_hashes.into_vec(),
+    })
+}
+
+pub fn parse_create_denom_reply(reply: &Reply) -> Result<MsgCreateDenomResponse, ReplyError> {
+    let response: tokenfactory_tx::MsgCreateDenomResponse = decode_reply(reply)?;
+    Ok(MsgCreateDenomResponse {
+        new_token_denom: response.new_token_denom,
+    })
+}
+
+/// Checks that a token factory `MsgMint`, `MsgBurn`, `MsgChangeAdmin` or `MsgSetDenomMetadata` sub-message succeeded.
+/// Their responses carry no fields, so any data present is ignored.
+pub fn parse_tokenfactory_empty_reply(reply: &Reply) -> Result<(), ReplyError> {
+    match &reply.result {
+        SubMsgResult::Ok(_) => Ok(()),
+        SubMsgResult::Err(err) => Err(ReplyError::SubMsgFailure {
+            id: reply.id,
+            err: err.to_owned(),
+        }),
+    }
+}
+
+fn decode_reply<M: Message>(reply: &Reply) -> Result<M, ReplyError> {
+    let id = reply.id;
+    let data = match &reply.result {
+        SubMsgResult::Ok(response) => response.data.as_ref().ok_or(ReplyError::MissingData { id })?,
+        SubMsgResult::Err(err) => {
+            return Err(ReplyError::SubMsgFailure { id, err: err.to_owned() });
+        }
+    };
+
+    M::parse_from_bytes(data.as_slice()).map_err(|err| ReplyError::Decode { id, err: err.to_string() })
+}
+
+fn parse_position_delta(id: u64, position_delta: &exchange::PositionDelta) -> Result<PositionDelta, ReplyError> {
+    Ok(PositionDelta {
+        is_long: position_delta.is_long,
+        execution_quantity: parse_sdk_dec(id, "results.position_delta.execution_quantity", &position_delta.execution_quantity)?,
+        execution_margin: parse_sdk_dec(id, "results.position_delta.execution_margin", &position_delta.execution_margin)?,
+        execution_price: parse_sdk_dec(id, "results.position_delta.execution_price", &position_delta.execution_price)?,
+    })
+}
+
+fn parse_sdk_dec(id: u64, field: &str, value: &str) -> Result<FPDecimal, ReplyError> {
+    FPDecimal::from_sdk_dec_str(value).map_err(|_| ReplyError::InvalidDecimal {
+        id,
+        field: field.to_string(),
+        value: value.to_string(),
+    })
+}
+
+fn missing_field(id: u64, field: &str) -> ReplyError {
+    ReplyError::MissingField {
+        id,
+        field: field.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Binary, Reply, SubMsgResponse, SubMsgResult};
+    use injective_math::FPDecimal;
+    use injective_protobuf::proto::exchange::{exchange, tx};
+    use protobuf::{Message, SingularPtrField};
+
+    use crate::reply::{
+        parse_batch_update_orders_reply, parse_create_derivative_market_order_reply, parse_create_spot_market_order_reply, ReplyError,
+    };
+
+    fn reply_with<M: Message>(id: u64, message: &M) -> Reply {
+        Reply {
+            id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(message.write_to_bytes().unwrap())),
+            }),
+        }
+    }
+
+    fn dec(value: &str) -> FPDecimal {
+        FPDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn parses_spot_market_order_reply() {
+        let mut results = tx::SpotMarketOrderResults::new();
+        results.quantity = "1500000000000000000".to_string();
+        results.price = "20250000000000000000".to_string();
+        results.fee = "30375000000000000".to_string();
+        let mut response = tx::MsgCreateSpotMarketOrderResponse::new();
+        response.order_hash = "0xabc".to_string();
+        response.results = SingularPtrField::some(results);
+
+        let parsed = parse_create_spot_market_order_reply(&reply_with(1, &response)).unwrap();
+        assert_eq!(parsed.order_hash, "0xabc");
+        assert_eq!(parsed.results.quantity, dec("1.5"));
+        assert_eq!(parsed.results.price, dec("20.25"));
+        assert_eq!(parsed.results.fee, dec("0.030375"));
+    }
+
+    #[test]
+    fn parses_derivative_market_order_reply() {
+        let mut position_delta = exchange::PositionDelta::new();
+        position_delta.is_long = true;
+        position_delta.execution_quantity = "2000000000000000000".to_string();
+        position_delta.execution_margin = "10000000000000000000".to_string();
+        position_delta.execution_price = "5000000000000000000".to_string();
+        let mut results = tx::DerivativeMarketOrderResults::new();
+        results.quantity = "2000000000000000000".to_string();
+        results.price = "5000000000000000000".to_string();
+        results.fee = "10000000000000000".to_string();
+        results.payout = "-1000000000000000000".to_string();
+        results.position_delta = SingularPtrField::some(position_delta);
+        let mut response = tx::MsgCreateDerivativeMarketOrderResponse::new();
+        response.order_hash = "0xdef".to_string();
+        response.results = SingularPtrField::some(results);
+
+        let parsed = parse_create_derivative_market_order_reply(&reply_with(2, &response)).unwrap();
+        assert_eq!(parsed.results.fee, dec("0.01"));
+        assert_eq!(parsed.results.payout, dec("-1"));
+        assert!(parsed.results.position_delta.is_long);
+        assert_eq!(parsed.results.position_delta.execution_margin, dec("10"));
+    }
+
+    #[test]
+    fn parses_batch_update_orders_reply() {
+        let mut response = tx::MsgBatchUpdateOrdersResponse::new();
+        response.spot_cancel_success = vec![true, false];
+        response.spot_order_hashes = vec!["0x01".to_string()].into();
+
+        let parsed = parse_batch_update_orders_reply(&reply_with(3, &response)).unwrap();
+        assert_eq!(parsed.spot_cancel_success, vec![true, false]);
+        assert_eq!(parsed.spot_order_hashes, vec!["0x01".to_string()]);
+        assert!(parsed.derivative_order_hashes.is_empty());
+    }
+
+    #[test]
+    fn reports_structured_errors() {
+        let failed = Reply {
+            id: 4,
+            result: SubMsgResult::Err("insufficient funds".to_string()),
+        };
+        assert_eq!(
+            parse_create_spot_market_order_reply(&failed).unwrap_err(),
+            ReplyError::SubMsgFailure {
+                id: 4,
+                err: "insufficient funds".to_string()
+            }
+        );
+
+        let no_data = Reply {
+            id: 5,
+            result: SubMsgResult::Ok(SubMsgResponse { events: vec![], data: None }),
+        };
+        assert_eq!(
+            parse_create_spot_market_order_reply(&no_data).unwrap_err(),
+            ReplyError::MissingData { id: 5 }
+        );
+
+        let response = tx::MsgCreateSpotMarketOrderResponse::new();
+        assert_eq!(
+            parse_create_spot_market_order_reply(&reply_with(6, &response)).unwrap_err(),
+            ReplyError::MissingField {
+                id: 6,
+                field: "results".to_string()
+            }
+        );
+
+        let mut results = tx::SpotMarketOrderResults::new();
+        results.quantity = "1.5".to_string();
+        let mut response = tx::MsgCreateSpotMarketOrderResponse::new();
+        response.results = SingularPtrField::some(results);
+        assert_eq!(
+            parse_create_spot_market_order_reply(&reply_with(7, &response)).unwrap_err(),
+            ReplyError::InvalidDecimal {
+                id: 7,
+                field: "results.quantity".to_string(),
+                value: "1.5".to_string()
+            }
+        );
+    }
+}
@@ -31,11 +31,17 @@ impl SpotLimitOrder {
 
 impl GenericOrder for SpotLimitOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        matches!(
+            self.order_type,
+            OrderType::Buy | OrderType::StopBuy | OrderType::TakeBuy | OrderType::BuyPo | OrderType::BuyAtomic
+        )
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        matches!(
+            self.order_type,
+            OrderType::Sell | OrderType::StopSell | OrderType::TakeSell | OrderType::SellPo | OrderType::SellAtomic
+        )
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -100,11 +106,17 @@ impl SpotOrder {
 
 impl GenericOrder for SpotOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        matches!(
+            self.order_type,
+            OrderType::Buy | OrderType::StopBuy | OrderType::TakeBuy | OrderType::BuyPo | OrderType::BuyAtomic
+        )
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        matches!(
+            self.order_type,
+            OrderType::Sell | OrderType::StopSell | OrderType::TakeSell | OrderType::SellPo | OrderType::SellAtomic
+        )
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -166,3 +178,8 @@ pub struct MsgCreateSpotMarketOrderResponse {
     pub order_hash: String,
     pub results: SpotMarketOrderResults,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MsgCreateSpotLimitOrderResponse {
+    pub order_hash: String,
+}
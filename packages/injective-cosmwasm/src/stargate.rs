@@ -0,0 +1,427 @@
+use cosmwasm_std::{Binary, CosmosMsg, StdError, StdResult};
+use injective_math::FPDecimal;
+use injective_protobuf::proto::exchange::tx;
+use injective_protobuf::proto::oracle::tx as oracle_tx;
+use injective_protobuf::proto::tokenfactory::tx as tokenfactory_tx;
+use injective_protobuf::proto::wasmx::tx as wasmx_tx;
+use protobuf::well_known_types::Any;
+use protobuf::{Message, SingularPtrField};
+
+use crate::proto_conversion::ToProto;
+use crate::{InjectiveMsg, InjectiveMsgWrapper};
+
+pub const EXCHANGE_TYPE_URL_PREFIX: &str = "/injective.exchange.v1beta1.";
+pub const ORACLE_TYPE_URL_PREFIX: &str = "/injective.oracle.v1beta1.";
+pub const TOKENFACTORY_TYPE_URL_PREFIX: &str = "/injective.tokenfactory.v1beta1.";
+pub const WASMX_TYPE_URL_PREFIX: &str = "/injective.wasmx.v1.";
+
+/// Encodes `msg` as a Stargate message, for contexts where the custom Injective route is not available (e.g. authz `MsgExec`)
+pub fn create_stargate_msg(msg: &InjectiveMsg) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    let any = encode_injective_msg(msg)?;
+    Ok(CosmosMsg::Stargate {
+        type_url: any.type_url,
+        value: Binary::from(any.value),
+    })
+}
+
+/// Moves a message built by the `create_*_msg` helpers onto the Stargate transport.
+/// Custom Injective messages are re-encoded as their protobuf `Any`, every other message is returned unchanged.
+pub fn to_stargate_msg(msg: CosmosMsg<InjectiveMsgWrapper>) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
+    match msg {
+        CosmosMsg::Custom(wrapper) => create_stargate_msg(&wrapper.msg_data),
+        msg => Ok(msg),
+    }
+}
+
+pub(crate) fn encode_injective_msg(msg: &InjectiveMsg) -> StdResult<Any> {
+    match msg {
+        InjectiveMsg::Deposit {
+            sender,
+            subaccount_id,
+            amount,
+        } => {
+            let mut proto = tx::MsgDeposit::new();
+            proto.sender = sender.to_string();
+            proto.subaccount_id = subaccount_id.as_str().to_string();
+            proto.amount = SingularPtrField::some(amount.to_proto());
+            to_any(&exchange_type_url("MsgDeposit"), &proto)
+        }
+        InjectiveMsg::Withdraw {
+            sender,
+            subaccount_id,
+            amount,
+        } => {
+            let mut proto = tx::MsgWithdraw::new();
+            proto.sender = sender.to_string();
+            proto.subaccount_id = subaccount_id.as_str().to_string();
+            proto.amount = SingularPtrField::some(amount.to_proto());
+            to_any(&exchange_type_url("MsgWithdraw"), &proto)
+        }
+        InjectiveMsg::SubaccountTransfer {
+            sender,
+            source_subaccount_id,
+            destination_subaccount_id,
+            amount,
+        } => {
+            let mut proto = tx::MsgSubaccountTransfer::new();
+            proto.sender = sender.to_string();
+            proto.source_subaccount_id = source_subaccount_id.as_str().to_string();
+            proto.destination_subaccount_id = destination_subaccount_id.as_str().to_string();
+            proto.amount = SingularPtrField::some(amount.to_proto());
+            to_any(&exchange_type_url("MsgSubaccountTransfer"), &proto)
+        }
+        InjectiveMsg::ExternalTransfer {
+            sender,
+            source_subaccount_id,
+            destination_subaccount_id,
+            amount,
+        } => {
+            let mut proto = tx::MsgExternalTransfer::new();
+            proto.sender = sender.to_string();
+            proto.source_subaccount_id = source_subaccount_id.as_str().to_string();
+            proto.destination_subaccount_id = destination_subaccount_id.as_str().to_string();
+            proto.amount = SingularPtrField::some(amount.to_proto());
+            to_any(&exchange_type_url("MsgExternalTransfer"), &proto)
+        }
+        InjectiveMsg::CreateSpotMarketOrder { sender, order } => {
+            let mut proto = tx::MsgCreateSpotMarketOrder::new();
+            proto.sender = sender.to_string();
+            proto.order = SingularPtrField::some(order.to_proto());
+            to_any(&exchange_type_url("MsgCreateSpotMarketOrder"), &proto)
+        }
+        InjectiveMsg::CreateDerivativeMarketOrder { sender, order } => {
+            let mut proto = tx::MsgCreateDerivativeMarketOrder::new();
+            proto.sender = sender.to_string();
+            proto.order = SingularPtrField::some(order.to_proto());
+            to_any(&exchange_type_url("MsgCreateDerivativeMarketOrder"), &proto)
+        }
+        InjectiveMsg::CancelSpotOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash,
+        } => {
+            let mut proto = tx::MsgCancelSpotOrder::new();
+            proto.sender = sender.to_string();
+            proto.market_id = market_id.as_str().to_string();
+            proto.subaccount_id = subaccount_id.as_str().to_string();
+            proto.order_hash = order_hash.to_owned();
+            to_any(&exchange_type_url("MsgCancelSpotOrder"), &proto)
+        }
+        InjectiveMsg::CancelDerivativeOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash,
+            order_mask,
+        } => {
+            let mut proto = tx::MsgCancelDerivativeOrder::new();
+            proto.sender = sender.to_string();
+            proto.market_id = market_id.as_str().to_string();
+            proto.subaccount_id = subaccount_id.as_str().to_string();
+            proto.order_hash = order_hash.to_owned();
+            proto.order_mask = *order_mask;
+            to_any(&exchange_type_url("MsgCancelDerivativeOrder"), &proto)
+        }
+        InjectiveMsg::IncreasePositionMargin {
+            sender,
+            source_subaccount_id,
+            destination_subaccount_id,
+            market_id,
+            amount,
+        } => {
+            let mut proto = tx::MsgIncreasePositionMargin::new();
+            proto.sender = sender.to_string();
+            proto.source_subaccount_id = source_subaccount_id.as_str().to_string();
+            proto.destination_subaccount_id = destination_subaccount_id.as_str().to_string();
+            proto.market_id = market_id.as_str().to_string();
+            proto.amount = FPDecimal::from(amount.amount).to_sdk_dec_str();
+            to_any(&exchange_type_url("MsgIncreasePositionMargin"), &proto)
+        }
+        InjectiveMsg::LiquidatePosition {
+            sender,
+            subaccount_id,
+            market_id,
+            order,
+        } => {
+            let mut proto = tx::MsgLiquidatePosition::new();
+            proto.sender = sender.to_string();
+            proto.subaccount_id = subaccount_id.as_str().to_string();
+            proto.market_id = market_id.as_str().to_string();
+            proto.order = SingularPtrField::from_option(order.as_ref().map(ToProto::to_proto));
+            to_any(&exchange_type_url("MsgLiquidatePosition"), &proto)
+        }
+        InjectiveMsg::BatchUpdateOrders {
+            sender,
+            subaccount_id,
+            spot_market_ids_to_cancel_all,
+            derivative_market_ids_to_cancel_all,
+            spot_orders_to_cancel,
+            derivative_orders_to_cancel,
+            spot_orders_to_create,
+            derivative_orders_to_create,
+        } => {
+            let mut proto = tx::MsgBatchUpdateOrders::new();
+            proto.sender = sender.to_string();
+            proto.subaccount_id = subaccount_id.as_ref().map(|id| id.as_str().to_string()).unwrap_or_default();
+            proto.spot_market_ids_to_cancel_all = spot_market_ids_to_cancel_all.iter().map(|id| id.as_str().to_string()).collect();
+            proto.derivative_market_ids_to_cancel_all = derivative_market_ids_to_cancel_all.iter().map(|id| id.as_str().to_string()).collect();
+            proto.spot_orders_to_cancel = spot_orders_to_cancel.iter().map(ToProto::to_proto).collect();
+            proto.derivative_orders_to_cancel = derivative_orders_to_cancel.iter().map(ToProto::to_proto).collect();
+            proto.spot_orders_to_create = spot_orders_to_create.iter().map(ToProto::to_proto).collect();
+            proto.derivative_orders_to_create = derivative_orders_to_create.iter().map(ToProto::to_proto).collect();
+            to_any(&exchange_type_url("MsgBatchUpdateOrders"), &proto)
+        }
+        InjectiveMsg::RegisterAsDMM { .. } => Err(StdError::generic_err("RegisterAsDMM has no Stargate message on chain")),
+        InjectiveMsg::RelayPythPrices { sender, price_attestations } => {
+            let mut proto = oracle_tx::MsgRelayPythPrices::new();
+            proto.sender = sender.to_string();
+            proto.price_attestations = price_attestations.iter().map(ToProto::to_proto).collect();
+            to_any(&oracle_type_url("MsgRelayPythPrices"), &proto)
+        }
+        InjectiveMsg::RelayPriceFeedPrice { sender, base, quote, price } => {
+            let mut proto = oracle_tx::MsgRelayPriceFeedPrice::new();
+            proto.sender = sender.to_string();
+            proto.base = base.to_owned().into();
+            proto.quote = quote.to_owned().into();
+            proto.price = price.iter().map(FPDecimal::to_sdk_dec_str).collect();
+            to_any(&oracle_type_url("MsgRelayPriceFeedPrice"), &proto)
+        }
+        InjectiveMsg::RelayProviderPrices {
+            sender,
+            provider,
+            symbols,
+            prices,
+        } => {
+            let mut proto = oracle_tx::MsgRelayProviderPrices::new();
+            proto.sender = sender.to_string();
+            proto.provider = provider.to_owned();
+            proto.symbols = symbols.to_owned().into();
+            proto.prices = prices.iter().map(FPDecimal::to_sdk_dec_str).collect();
+            to_any(&oracle_type_url("MsgRelayProviderPrices"), &proto)
+        }
+        InjectiveMsg::RequestBandIBCRates { sender, request_id } => {
+            let mut proto = oracle_tx::MsgRequestBandIBCRates::new();
+            proto.sender = sender.to_string();
+            proto.request_id = *request_id;
+            to_any(&oracle_type_url("MsgRequestBandIBCRates"), &proto)
+        }
+        InjectiveMsg::CreateDenom { sender, subdenom } => {
+            let mut proto = tokenfactory_tx::MsgCreateDenom::new();
+            proto.sender = sender.to_owned();
+            proto.subdenom = subdenom.to_owned();
+            to_any(&tokenfactory_type_url("MsgCreateDenom"), &proto)
+        }
+        InjectiveMsg::Mint { sender, amount, mint_to } => {
+            if mint_to != sender.as_str() {
+                return Err(StdError::generic_err(format!(
+                    "MsgMint always mints to the sender, cannot mint to {mint_to}"
+                )));
+            }
+            let mut proto = tokenfactory_tx::MsgMint::new();
+            proto.sender = sender.to_string();
+            proto.amount = SingularPtrField::some(amount.to_proto());
+            to_any(&tokenfactory_type_url("MsgMint"), &proto)
+        }
+        InjectiveMsg::Burn { sender, amount } => {
+            let mut proto = tokenfactory_tx::MsgBurn::new();
+            proto.sender = sender.to_string();
+            proto.amount = SingularPtrField::some(amount.to_proto());
+            to_any(&tokenfactory_type_url("MsgBurn"), &proto)
+        }
+        InjectiveMsg::SetTokenMetadata { .. } => Err(StdError::generic_err(
+            "SetTokenMetadata carries no sender, MsgSetDenomMetadata cannot be built from it",
+        )),
+        InjectiveMsg::UpdateContract {
+            sender,
+            contract_address,
+            gas_limit,
+            gas_price,
+            admin_address,
+        } => {
+            let mut proto = wasmx_tx::MsgUpdateContract::new();
+            proto.sender = sender.to_string();
+            proto.contract_address = contract_address.to_string();
+            proto.gas_limit = *gas_limit;
+            proto.gas_price = *gas_price;
+            proto.admin_address = admin_address.to_owned();
+            to_any(&wasmx_type_url("MsgUpdateContract"), &proto)
+        }
+        InjectiveMsg::ActivateContract { sender, contract_address } => {
+            let mut proto = wasmx_tx::MsgActivateContract::new();
+            proto.sender = sender.to_string();
+            proto.contract_address = contract_address.to_string();
+            to_any(&wasmx_type_url("MsgActivateContract"), &proto)
+        }
+        InjectiveMsg::DeactivateContract { sender, contract_address } => {
+            let mut proto = wasmx_tx::MsgDeactivateContract::new();
+            proto.sender = sender.to_string();
+            proto.contract_address = contract_address.to_string();
+            to_any(&wasmx_type_url("MsgDeactivateContract"), &proto)
+        }
+    }
+}
+
+pub(crate) fn exchange_type_url(name: &str) -> String {
+    format!("{EXCHANGE_TYPE_URL_PREFIX}{name}")
+}
+
+fn oracle_type_url(name: &str) -> String {
+    format!("{ORACLE_TYPE_URL_PREFIX}{name}")
+}
+
+fn tokenfactory_type_url(name: &str) -> String {
+    format!("{TOKENFACTORY_TYPE_URL_PREFIX}{name}")
+}
+
+fn wasmx_type_url(name: &str) -> String {
+    format!("{WASMX_TYPE_URL_PREFIX}{name}")
+}
+
+pub(crate) fn to_any<M: Message>(type_url: &str, message: &M) -> StdResult<Any> {
+    let value = message
+        .write_to_bytes()
+        .map_err(|e| StdError::generic_err(format!("Failed to encode {type_url}: {e}")))?;
+
+    let mut any = Any::new();
+    any.type_url = type_url.to_string();
+    any.value = value;
+    Ok(any)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, StdError};
+    use injective_math::FPDecimal;
+    use injective_protobuf::proto::exchange::{exchange, tx};
+    use injective_protobuf::proto::oracle::tx as oracle_tx;
+    use injective_protobuf::proto::tokenfactory::tx as tokenfactory_tx;
+    use protobuf::Message;
+
+    use crate::stargate::{create_stargate_msg, to_stargate_msg};
+    use crate::{
+        create_mint_tokens_msg, create_register_as_dmm_msg, create_relay_price_feed_price_msg, InjectiveMsg, MarketId, OrderType, SpotOrder,
+        SubaccountId,
+    };
+
+    const SUBACCOUNT_ID: &str = "0xb5e09b93aceb70c1b47cb1e9e5a2d3d4a52d9d20000000000000000000000001";
+
+    #[test]
+    fn encodes_spot_market_order() {
+        let order = SpotOrder::new(
+            FPDecimal::from_str("1.5").unwrap(),
+            FPDecimal::from(10u128),
+            OrderType::BuyAtomic,
+            &MarketId::unchecked("0x01"),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+        );
+        let msg = InjectiveMsg::CreateSpotMarketOrder {
+            sender: Addr::unchecked("inj1sender"),
+            order,
+        };
+
+        match create_stargate_msg(&msg).unwrap() {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, "/injective.exchange.v1beta1.MsgCreateSpotMarketOrder");
+                let proto = tx::MsgCreateSpotMarketOrder::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(proto.sender, "inj1sender");
+                let order = proto.get_order();
+                assert_eq!(order.market_id, "0x01");
+                assert_eq!(order.order_type, exchange::OrderType::BUY_ATOMIC);
+                assert_eq!(order.get_order_info().subaccount_id, SUBACCOUNT_ID);
+                assert_eq!(order.get_order_info().price, "1500000000000000000");
+                assert_eq!(order.get_order_info().quantity, "10000000000000000000");
+                assert_eq!(order.trigger_price, "");
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn encodes_deposit() {
+        let msg = InjectiveMsg::Deposit {
+            sender: Addr::unchecked("inj1sender"),
+            subaccount_id: SubaccountId::unchecked(SUBACCOUNT_ID),
+            amount: Coin::new(100, "inj"),
+        };
+
+        match create_stargate_msg(&msg).unwrap() {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, "/injective.exchange.v1beta1.MsgDeposit");
+                let proto = tx::MsgDeposit::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(proto.subaccount_id, SUBACCOUNT_ID);
+                assert_eq!(proto.get_amount().amount, "100");
+                assert_eq!(proto.get_amount().denom, "inj");
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn moves_custom_messages_to_stargate() {
+        let msg = create_relay_price_feed_price_msg(
+            Addr::unchecked("inj1sender"),
+            vec!["INJ".to_string()],
+            vec!["USDT".to_string()],
+            vec![FPDecimal::from_str("0.5").unwrap()],
+        )
+        .unwrap();
+
+        match to_stargate_msg(msg).unwrap() {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, "/injective.oracle.v1beta1.MsgRelayPriceFeedPrice");
+                let proto = oracle_tx::MsgRelayPriceFeedPrice::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(proto.base.to_vec(), vec!["INJ".to_string()]);
+                assert_eq!(proto.quote.to_vec(), vec!["USDT".to_string()]);
+                assert_eq!(proto.price.to_vec(), vec!["500000000000000000".to_string()]);
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+
+        let bank_msg: CosmosMsg<_> = BankMsg::Burn {
+            amount: vec![Coin::new(1, "inj")],
+        }
+        .into();
+        assert_eq!(to_stargate_msg(bank_msg.clone()).unwrap(), bank_msg);
+    }
+
+    #[test]
+    fn encodes_tokenfactory_mint_to_sender_only() {
+        let msg = create_mint_tokens_msg(
+            Addr::unchecked("inj1sender"),
+            Coin::new(5, "factory/inj1sender/lp"),
+            "inj1sender".to_string(),
+        );
+        match to_stargate_msg(msg).unwrap() {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, "/injective.tokenfactory.v1beta1.MsgMint");
+                let proto = tokenfactory_tx::MsgMint::parse_from_bytes(value.as_slice()).unwrap();
+                assert_eq!(proto.sender, "inj1sender");
+                assert_eq!(proto.get_amount().amount, "5");
+            }
+            msg => panic!("Unexpected message {:?}", msg),
+        }
+
+        let msg = create_mint_tokens_msg(
+            Addr::unchecked("inj1sender"),
+            Coin::new(5, "factory/inj1sender/lp"),
+            "inj1other".to_string(),
+        );
+        assert_eq!(
+            to_stargate_msg(msg).unwrap_err(),
+            StdError::generic_err("MsgMint always mints to the sender, cannot mint to inj1other")
+        );
+    }
+
+    #[test]
+    fn rejects_messages_without_chain_equivalent() {
+        let msg = create_register_as_dmm_msg(Addr::unchecked("inj1sender"), "inj1dmm".to_string());
+        assert_eq!(
+            to_stargate_msg(msg).unwrap_err(),
+            StdError::generic_err("RegisterAsDMM has no Stargate message on chain")
+        );
+    }
+}
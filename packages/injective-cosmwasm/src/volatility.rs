@@ -1,4 +1,5 @@
-use injective_math::FPDecimal;
+use cosmwasm_std::{StdError, StdResult};
+use injective_math::{annualized_volatility, FPDecimal, SECONDS_PER_YEAR};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -25,13 +26,191 @@ pub struct TradeHistoryOptions {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PriceRecord {
-    timestamp: i64,
-    price: FPDecimal,
+    pub timestamp: i64,
+    pub price: FPDecimal,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TradeRecord {
-    timestamp: i64,
-    price: FPDecimal,
-    quantity: FPDecimal,
+    pub timestamp: i64,
+    pub price: FPDecimal,
+    pub quantity: FPDecimal,
+}
+
+/// A price observed at a point in time, so `injective_math::stats` can run on price and trade history
+pub trait TimestampedPrice {
+    fn timestamp(&self) -> i64;
+    fn price(&self) -> FPDecimal;
+}
+
+impl TimestampedPrice for PriceRecord {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn price(&self) -> FPDecimal {
+        self.price
+    }
+}
+
+impl TimestampedPrice for TradeRecord {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn price(&self) -> FPDecimal {
+        self.price
+    }
+}
+
+/// Prices of `records` in timestamp order
+pub fn price_series<R: TimestampedPrice>(records: &[R]) -> Vec<FPDecimal> {
+    let mut sorted_records: Vec<&R> = records.iter().collect();
+    sorted_records.sort_by_key(|record| record.timestamp());
+    sorted_records.iter().map(|record| record.price()).collect()
+}
+
+/// Annualized volatility of the log returns between consecutive records.
+/// The records are assumed to be evenly spaced over the time they span, as with grouped trade history.
+pub fn annualized_realized_volatility<R: TimestampedPrice>(records: &[R]) -> StdResult<FPDecimal> {
+    let first_timestamp = records.iter().map(|record| record.timestamp()).min();
+    let last_timestamp = records.iter().map(|record| record.timestamp()).max();
+    let span = match (first_timestamp, last_timestamp) {
+        (Some(first), Some(last)) if last > first => last - first,
+        _ => return Err(StdError::generic_err("Records must span a positive amount of time")),
+    };
+
+    let periods = FPDecimal::from((records.len() - 1) as u128);
+    let periods_per_year = FPDecimal::from(SECONDS_PER_YEAR as i128) * periods / FPDecimal::from(span as i128);
+    annualized_volatility(&price_series(records), periods_per_year)
+}
+
+/// Time-weighted average price of `records` over the `window` seconds ending at `end_timestamp`.
+/// Each record's price is assumed to hold until the next record, the last one until `end_timestamp`.
+/// Fails unless a record at or before the start of the window makes the prices cover all of it.
+pub fn time_weighted_average_price(records: &[PriceRecord], end_timestamp: i64, window: i64) -> StdResult<FPDecimal> {
+    if window <= 0 {
+        return Err(StdError::generic_err("TWAP window must be positive"));
+    }
+    let start_timestamp = end_timestamp
+        .checked_sub(window)
+        .ok_or_else(|| StdError::generic_err(format!("TWAP window of {window}s ending at {end_timestamp} is out of range")))?;
+
+    let mut sorted_records: Vec<&PriceRecord> = records.iter().filter(|record| record.timestamp < end_timestamp).collect();
+    sorted_records.sort_by_key(|record| record.timestamp);
+
+    let mut weighted_sum = FPDecimal::zero();
+    let mut covered_time = 0i64;
+    for (i, record) in sorted_records.iter().enumerate() {
+        let next_timestamp = sorted_records.get(i + 1).map_or(end_timestamp, |next| next.timestamp);
+        let from = record.timestamp.max(start_timestamp);
+        let to = next_timestamp.min(end_timestamp);
+        if to <= from {
+            continue;
+        }
+
+        let duration = to - from;
+        weighted_sum = weighted_sum.checked_add(record.price.checked_mul(FPDecimal::from(duration as i128))?)?;
+        covered_time += duration;
+    }
+
+    if covered_time == 0 {
+        return Err(StdError::generic_err("No price records in TWAP window"));
+    }
+    if covered_time < window {
+        return Err(StdError::generic_err(format!(
+            "Price records cover only {covered_time}s of the {window}s TWAP window"
+        )));
+    }
+
+    weighted_sum.checked_div(FPDecimal::from(window as i128))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::StdError;
+    use injective_math::FPDecimal;
+
+    use crate::volatility::{annualized_realized_volatility, price_series, time_weighted_average_price, PriceRecord, TradeRecord};
+
+    fn record(timestamp: i64, price: &str) -> PriceRecord {
+        PriceRecord {
+            timestamp,
+            price: FPDecimal::from_str(price).unwrap(),
+        }
+    }
+
+    #[test]
+    fn twap_weights_prices_by_duration() {
+        let records = vec![record(100, "10"), record(130, "20"), record(160, "40")];
+
+        // 10 for 30s, 20 for 30s, 40 for 40s
+        let twap = time_weighted_average_price(&records, 200, 100).unwrap();
+        assert_eq!(twap, FPDecimal::from_str("25").unwrap());
+    }
+
+    #[test]
+    fn twap_clips_records_to_window() {
+        let records = vec![record(160, "40"), record(0, "10"), record(130, "20")];
+
+        // 10 for 10s, 20 for 30s, 40 for 10s
+        let twap = time_weighted_average_price(&records, 170, 50).unwrap();
+        assert_eq!(twap, FPDecimal::from_str("22").unwrap());
+    }
+
+    #[test]
+    fn twap_without_records_fails() {
+        let err = time_weighted_average_price(&[record(300, "1")], 200, 100).unwrap_err();
+        assert_eq!(err, StdError::generic_err("No price records in TWAP window"));
+
+        let err = time_weighted_average_price(&[], 200, 0).unwrap_err();
+        assert_eq!(err, StdError::generic_err("TWAP window must be positive"));
+
+        assert!(time_weighted_average_price(&[record(0, "1")], i64::MIN, 1).is_err());
+    }
+
+    #[test]
+    fn twap_reports_overflow() {
+        let records = vec![PriceRecord {
+            timestamp: 0,
+            price: FPDecimal::MAX,
+        }];
+        assert!(matches!(time_weighted_average_price(&records, 100, 100), Err(StdError::Overflow { .. })));
+    }
+
+    #[test]
+    fn twap_requires_records_covering_the_window() {
+        let records = vec![record(150, "10"), record(180, "20")];
+        let err = time_weighted_average_price(&records, 200, 100).unwrap_err();
+        assert_eq!(err, StdError::generic_err("Price records cover only 50s of the 100s TWAP window"));
+
+        // a record exactly at the start of the window covers it
+        let twap = time_weighted_average_price(&records, 200, 50).unwrap();
+        assert_eq!(twap, FPDecimal::from_str("14").unwrap());
+    }
+
+    #[test]
+    fn realized_volatility_of_daily_trades() {
+        let trade = |timestamp: i64, price: &str| TradeRecord {
+            timestamp,
+            price: FPDecimal::from_str(price).unwrap(),
+            quantity: FPDecimal::ONE,
+        };
+        let day = 24 * 60 * 60;
+        let trades = vec![trade(2 * day, "99"), trade(0, "100"), trade(day, "110")];
+
+        assert_eq!(
+            price_series(&trades),
+            vec![FPDecimal::from(100u128), FPDecimal::from(110u128), FPDecimal::from(99u128)]
+        );
+
+        // daily log returns of 100 -> 110 -> 99, scaled by sqrt(365)
+        let volatility = annualized_realized_volatility(&trades).unwrap();
+        assert!(volatility.abs_diff(&FPDecimal::from_str("2.710911813975248656").unwrap()) < FPDecimal::from_str("0.000000000001").unwrap());
+
+        assert!(annualized_realized_volatility(&trades[..1]).is_err());
+        assert!(annualized_realized_volatility::<PriceRecord>(&[]).is_err());
+    }
 }
@@ -0,0 +1,144 @@
+use cosmwasm_std::{Addr, StdError, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::query::RegisteredContract;
+use crate::InjectiveQuerier;
+
+/// WasmxSudoMsg is the set of sudo messages the wasmx module sends to registered contracts
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WasmxSudoMsg {
+    /// Executed at the beginning of every block while the contract is registered and active
+    BeginBlocker {},
+    /// Sent once when the contract is deregistered from wasmx
+    Deregister {},
+    /// Sent when the contract is deactivated and stops receiving BeginBlocker calls
+    Deactivate {},
+}
+
+/// Gas allotment of a contract registered for BeginBlocker execution
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+pub struct GasBudget {
+    // limit of gas per BB execution
+    pub gas_limit: u64,
+    // gas price that contract is willing to pay for execution in BeginBlocker
+    pub gas_price: u64,
+}
+
+impl GasBudget {
+    pub fn new(gas_limit: u64, gas_price: u64) -> Self {
+        GasBudget { gas_limit, gas_price }
+    }
+
+    /// Maximum fee (in the chain's base denom) the contract pays for a single BeginBlocker execution
+    pub fn max_fee(&self) -> Uint128 {
+        Uint128::from(self.gas_limit).saturating_mul(Uint128::from(self.gas_price))
+    }
+
+    /// Gas left once `reserved_gas` is set aside for the fixed cost of the execution
+    pub fn available_gas(&self, reserved_gas: u64) -> u64 {
+        self.gas_limit.saturating_sub(reserved_gas)
+    }
+
+    /// Number of work units costing `gas_per_unit` each that fit into the budget after `reserved_gas` is set aside
+    pub fn max_work_units(&self, gas_per_unit: u64, reserved_gas: u64) -> u64 {
+        if gas_per_unit == 0 {
+            return 0;
+        }
+        self.available_gas(reserved_gas) / gas_per_unit
+    }
+
+    pub fn fits(&self, estimated_gas: u64) -> bool {
+        estimated_gas <= self.gas_limit
+    }
+}
+
+impl From<&RegisteredContract> for GasBudget {
+    fn from(contract: &RegisteredContract) -> Self {
+        GasBudget::new(contract.gas_limit, contract.gas_price)
+    }
+}
+
+/// Returns the BeginBlocker gas budget of a registered contract, failing if the contract is not registered or not executable
+pub fn query_begin_blocker_gas_budget(querier: &InjectiveQuerier, contract_address: &Addr) -> StdResult<GasBudget> {
+    let contract_address = contract_address.to_string();
+    let registration = querier.query_contract_registration_info(&contract_address)?;
+
+    match registration.contract {
+        Some(contract) if contract.is_executable => Ok(GasBudget::from(&contract)),
+        Some(_) => Err(StdError::generic_err(format!("Contract {contract_address} is not executable"))),
+        None => Err(StdError::not_found(format!("Registered contract {contract_address}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, QuerierWrapper, StdError, Uint128};
+
+    use crate::exchange_mock_querier::handlers::create_registered_contract_info_query_handler;
+    use crate::query::RegisteredContract;
+    use crate::wasmx::{query_begin_blocker_gas_budget, GasBudget, WasmxSudoMsg};
+    use crate::{mock_dependencies, InjectiveQuerier};
+
+    fn registered_contract(is_executable: bool) -> RegisteredContract {
+        RegisteredContract {
+            gas_limit: 100_000,
+            gas_price: 1_000,
+            is_executable,
+            code_id: 1,
+            admin_address: "".to_string(),
+        }
+    }
+
+    #[test]
+    fn sudo_msg_serialization() {
+        assert_eq!(
+            serde_json_wasm::to_string(&WasmxSudoMsg::BeginBlocker {}).unwrap(),
+            r#"{"begin_blocker":{}}"#
+        );
+        assert_eq!(serde_json_wasm::to_string(&WasmxSudoMsg::Deregister {}).unwrap(), r#"{"deregister":{}}"#);
+        assert_eq!(
+            serde_json_wasm::from_str::<WasmxSudoMsg>(r#"{"deactivate":{}}"#).unwrap(),
+            WasmxSudoMsg::Deactivate {}
+        );
+    }
+
+    #[test]
+    fn gas_budget_work_units() {
+        let budget = GasBudget::new(100_000, 1_000);
+        assert_eq!(budget.max_fee(), Uint128::from(100_000_000u128));
+        assert_eq!(budget.max_work_units(3_000, 10_000), 30);
+        assert_eq!(budget.max_work_units(3_000, 200_000), 0);
+        assert_eq!(budget.max_work_units(0, 0), 0);
+        assert!(budget.fits(100_000));
+        assert!(!budget.fits(100_001));
+    }
+
+    #[test]
+    fn query_gas_budget_of_registered_contract() {
+        let mut deps = mock_dependencies();
+        deps.querier.registered_contract_info_query_handler = create_registered_contract_info_query_handler(Some(registered_contract(true)));
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+
+        let budget = query_begin_blocker_gas_budget(&querier, &Addr::unchecked("contract")).unwrap();
+        assert_eq!(budget, GasBudget::new(100_000, 1_000));
+    }
+
+    #[test]
+    fn query_gas_budget_fails_for_inactive_or_missing_contract() {
+        let mut deps = mock_dependencies();
+        deps.querier.registered_contract_info_query_handler = create_registered_contract_info_query_handler(Some(registered_contract(false)));
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+        let err = query_begin_blocker_gas_budget(&querier, &Addr::unchecked("contract")).unwrap_err();
+        assert_eq!(err, StdError::generic_err("Contract contract is not executable"));
+
+        let deps = mock_dependencies();
+        let querier_wrapper = QuerierWrapper::new(&deps.querier);
+        let querier = InjectiveQuerier::new(&querier_wrapper);
+        let err = query_begin_blocker_gas_budget(&querier, &Addr::unchecked("contract")).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { .. }));
+    }
+}
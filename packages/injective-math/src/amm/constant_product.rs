@@ -0,0 +1,96 @@
+//! Constant product pools, x * y = k
+use cosmwasm_std::{StdError, StdResult};
+
+use crate::amm::{after_fee, ensure_non_negative, ensure_positive, impact};
+use crate::fp_decimal::{FPDecimal, RoundingMode};
+
+/// Amount of the output token received for exactly `amount_in`, with `fee` taken from the input, rounded down
+pub fn swap_out(reserve_in: FPDecimal, reserve_out: FPDecimal, amount_in: FPDecimal, fee: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_positive(&[reserve_in, reserve_out])?;
+    ensure_non_negative(amount_in)?;
+    let amount_in = amount_in.checked_mul(after_fee(fee)?)?;
+    reserve_out.checked_mul(amount_in)?.checked_div(reserve_in.checked_add(amount_in)?)
+}
+
+/// Amount of the input token, fee included, required to receive exactly `amount_out`, rounded up
+pub fn swap_in(reserve_in: FPDecimal, reserve_out: FPDecimal, amount_out: FPDecimal, fee: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_positive(&[reserve_in, reserve_out])?;
+    ensure_non_negative(amount_out)?;
+    if amount_out >= reserve_out {
+        return Err(StdError::generic_err(format!(
+            "Cannot take {amount_out} out of a reserve of {reserve_out}"
+        )));
+    }
+    let amount_in = reserve_in
+        .checked_mul_round(amount_out, RoundingMode::Ceil)?
+        .checked_div_round(reserve_out.checked_sub(amount_out)?, RoundingMode::Ceil)?;
+    amount_in.checked_div_round(after_fee(fee)?, RoundingMode::Ceil)
+}
+
+/// Marginal price of the input token in units of the output token
+pub fn spot_price(reserve_in: FPDecimal, reserve_out: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_positive(&[reserve_in, reserve_out])?;
+    reserve_out.checked_div(reserve_in)
+}
+
+/// Relative shortfall of the execution price of a fee-less swap of `amount_in` against the spot price
+pub fn price_impact(reserve_in: FPDecimal, reserve_out: FPDecimal, amount_in: FPDecimal) -> StdResult<FPDecimal> {
+    let amount_out = swap_out(reserve_in, reserve_out, amount_in, FPDecimal::zero())?;
+    impact(spot_price(reserve_in, reserve_out)?, amount_in, amount_out)
+}
+
+/// Shares minted for the first deposit, the geometric mean sqrt(x * y) of the two amounts
+pub fn initial_shares(amount_x: FPDecimal, amount_y: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_positive(&[amount_x, amount_y])?;
+    // sqrt(x) * sqrt(y) keeps large deposits from overflowing the product
+    let root_x = FPDecimal::sqrt(amount_x).unwrap();
+    let root_y = FPDecimal::sqrt(amount_y).unwrap();
+    Ok(root_x.checked_mul(root_y)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amm::constant_product::*;
+    use crate::test_utils::dec;
+    use crate::FPDecimal;
+
+    #[test]
+    fn swaps_keep_the_invariant() {
+        let (reserve_in, reserve_out) = (dec("1000"), dec("2000"));
+        let out = swap_out(reserve_in, reserve_out, dec("100"), FPDecimal::zero()).unwrap();
+        assert_eq!(out, dec("181.818181818181818181"));
+
+        // 0.3% fee: 99.7 * 2000 / 1099.7
+        let out = swap_out(reserve_in, reserve_out, dec("100"), dec("0.003")).unwrap();
+        assert_eq!(out, dec("181.322178776029826316"));
+
+        let amount_in = swap_in(reserve_in, reserve_out, out, dec("0.003")).unwrap();
+        assert!(amount_in.abs_diff(&dec("100")) < dec("0.000000000001"));
+
+        assert!(swap_in(reserve_in, reserve_out, reserve_out, FPDecimal::zero()).is_err());
+        assert!(swap_out(reserve_in, reserve_out, dec("1"), FPDecimal::ONE).is_err());
+        assert!(swap_out(FPDecimal::zero(), reserve_out, dec("1"), FPDecimal::zero()).is_err());
+    }
+
+    #[test]
+    fn swaps_round_against_the_trader() {
+        // exact values 2000 / 1001 = 1.998001998001998001998... and 1000 / 1999 = 0.500250125062531265632...
+        let (reserve_in, reserve_out) = (dec("1000"), dec("2000"));
+        assert_eq!(
+            swap_out(reserve_in, reserve_out, dec("1"), FPDecimal::zero()).unwrap(),
+            dec("1.998001998001998001")
+        );
+        assert_eq!(
+            swap_in(reserve_in, reserve_out, dec("1"), FPDecimal::zero()).unwrap(),
+            dec("0.500250125062531266")
+        );
+    }
+
+    #[test]
+    fn prices_and_shares() {
+        assert_eq!(spot_price(dec("1000"), dec("2000")).unwrap(), FPDecimal::TWO);
+        // a trade of 10% of the input reserve moves the execution price by 1 - 1 / 1.1
+        assert_eq!(price_impact(dec("1000"), dec("2000"), dec("100")).unwrap(), dec("0.090909090909090910"));
+        assert_eq!(initial_shares(dec("100"), dec("400")).unwrap(), dec("200"));
+    }
+}
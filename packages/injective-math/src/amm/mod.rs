@@ -0,0 +1,114 @@
+//! Pool math for automated market makers, on checked `FPDecimal` arithmetic.
+//! `swap_out` is the amount received for an exact input and `swap_in` the input required for an exact output;
+//! fees are fractions of the traded amount (0.003 = 0.3%). Spot prices are in units of the output token per input token.
+use bigint::U256;
+use cosmwasm_std::{StdError, StdResult};
+
+use crate::fp_decimal::FPDecimal;
+
+pub mod constant_product;
+pub mod stableswap;
+pub mod weighted;
+
+/// Pool shares minted for depositing `deposits` into a pool holding `reserves` with `total_supply` shares.
+/// Deposits in excess of the pool's ratio are not credited, only the most constrained token counts.
+pub fn proportional_mint(total_supply: FPDecimal, reserves: &[FPDecimal], deposits: &[FPDecimal]) -> StdResult<FPDecimal> {
+    ensure_matching(reserves, deposits)?;
+    ensure_positive(reserves)?;
+    let mut shares: Option<FPDecimal> = None;
+    for (&reserve, &deposit) in reserves.iter().zip(deposits) {
+        let minted = total_supply.checked_mul(deposit)?.checked_div(reserve)?;
+        shares = Some(shares.map_or(minted, |shares| shares.min(minted)));
+    }
+    Ok(shares.unwrap_or_default())
+}
+
+/// Amounts of every reserve paid out for burning `shares` of `total_supply`
+pub fn proportional_burn(total_supply: FPDecimal, reserves: &[FPDecimal], shares: FPDecimal) -> StdResult<Vec<FPDecimal>> {
+    if shares > total_supply || total_supply.is_zero() {
+        return Err(StdError::generic_err(format!("Cannot burn {shares} of {total_supply} shares")));
+    }
+    reserves
+        .iter()
+        .map(|reserve| reserve.checked_mul(shares)?.checked_div(total_supply))
+        .collect()
+}
+
+/// The smallest representable amount, used to nudge results whose rounding would otherwise favour the trader
+pub(crate) fn unit() -> FPDecimal {
+    FPDecimal::from(U256::one())
+}
+
+/// 1 - fee, for fees in [0, 1)
+pub(crate) fn after_fee(fee: FPDecimal) -> StdResult<FPDecimal> {
+    if fee.is_negative() || fee >= FPDecimal::ONE {
+        return Err(StdError::generic_err(format!("Fee must be in [0, 1), got {fee}")));
+    }
+    Ok(FPDecimal::ONE.checked_sub(fee)?)
+}
+
+pub(crate) fn ensure_positive(values: &[FPDecimal]) -> StdResult<()> {
+    if values.iter().any(|value| value.is_negative() || value.is_zero()) {
+        return Err(StdError::generic_err("Pool reserves must be positive"));
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_non_negative(amount: FPDecimal) -> StdResult<()> {
+    if amount.is_negative() {
+        return Err(StdError::generic_err(format!("Trade amount must not be negative, got {amount}")));
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_matching(reserves: &[FPDecimal], amounts: &[FPDecimal]) -> StdResult<()> {
+    if reserves.is_empty() || reserves.len() != amounts.len() {
+        return Err(StdError::generic_err(format!(
+            "Expected {} amounts, got {}",
+            reserves.len(),
+            amounts.len()
+        )));
+    }
+    Ok(())
+}
+
+/// 1 - (amount_out / amount_in) / spot_price, the relative shortfall against trading at the spot price
+pub(crate) fn impact(spot_price: FPDecimal, amount_in: FPDecimal, amount_out: FPDecimal) -> StdResult<FPDecimal> {
+    let execution_price = amount_out.checked_div(amount_in)?;
+    Ok(FPDecimal::ONE.checked_sub(execution_price.checked_div(spot_price)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amm::{constant_product, proportional_burn, proportional_mint, stableswap, weighted};
+    use crate::test_utils::{dec, decs};
+    use crate::FPDecimal;
+
+    #[test]
+    fn proportional_liquidity() {
+        let reserves = decs(&["1000", "4000"]);
+        let supply = FPDecimal::from(2000u128);
+
+        // the second deposit is above the pool ratio, only 10% of the pool is credited
+        let shares = proportional_mint(supply, &reserves, &decs(&["100", "500"])).unwrap();
+        assert_eq!(shares, FPDecimal::from(200u128));
+
+        assert_eq!(proportional_burn(supply, &reserves, shares).unwrap(), decs(&["100", "400"]));
+        assert!(proportional_burn(supply, &reserves, FPDecimal::from(2001u128)).is_err());
+        assert!(proportional_mint(supply, &reserves, &decs(&["1"])).is_err());
+    }
+
+    #[test]
+    fn rejects_negative_trade_amounts() {
+        let (balance_in, balance_out, weight, fee) = (dec("1000"), dec("2000"), dec("0.5"), FPDecimal::zero());
+        let amount = dec("-100");
+        assert!(constant_product::swap_out(balance_in, balance_out, amount, fee).is_err());
+        assert!(constant_product::swap_in(balance_in, balance_out, amount, fee).is_err());
+        assert!(weighted::swap_out(balance_in, weight, balance_out, weight, amount, fee).is_err());
+        assert!(weighted::swap_in(balance_in, weight, balance_out, weight, amount, fee).is_err());
+
+        let balances = decs(&["1000", "1000"]);
+        assert!(stableswap::swap_out(&balances, dec("100"), 0, 1, amount, fee).is_err());
+        assert!(stableswap::swap_in(&balances, dec("100"), 0, 1, amount, fee).is_err());
+    }
+}
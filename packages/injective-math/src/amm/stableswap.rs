@@ -0,0 +1,258 @@
+//! Curve style stableswap pools. With `amp` the amplification coefficient A, n tokens and S = sum(x_i),
+//! the invariant D satisfies A * n^n * S + D = A * n^n * D + D^(n + 1) / (n^n * prod(x_i)).
+use cosmwasm_std::{StdError, StdResult};
+
+use crate::amm::{after_fee, ensure_matching, ensure_non_negative, ensure_positive, impact, unit};
+use crate::fp_decimal::{FPDecimal, RoundingMode};
+
+const MAX_ITERATIONS: usize = 255;
+
+fn count(len: usize) -> FPDecimal {
+    FPDecimal::from(len as u128)
+}
+
+// A * n^n
+fn amp_times_n_pow_n(amp: FPDecimal, n: usize) -> StdResult<FPDecimal> {
+    if amp.is_negative() || amp.is_zero() {
+        return Err(StdError::generic_err("Amplification must be positive"));
+    }
+    Ok(amp.checked_mul(count(n).checked_powi(n as i64)?)?)
+}
+
+fn ensure_pair(balances: &[FPDecimal], index_in: usize, index_out: usize) -> StdResult<()> {
+    if balances.len() < 2 {
+        return Err(StdError::generic_err("A stableswap pool needs at least two tokens"));
+    }
+    if index_in == index_out || index_in >= balances.len() || index_out >= balances.len() {
+        return Err(StdError::generic_err(format!(
+            "Invalid token pair {index_in} and {index_out} for a pool of {} tokens",
+            balances.len()
+        )));
+    }
+    Ok(())
+}
+
+// D^(n + 1) / (n^n * prod(x_i)), built up one balance at a time to keep the intermediates small
+fn d_product(balances: &[FPDecimal], d: FPDecimal) -> StdResult<FPDecimal> {
+    let n = count(balances.len());
+    balances
+        .iter()
+        .try_fold(d, |d_p, &balance| d_p.checked_mul(d)?.checked_div(balance.checked_mul(n)?))
+}
+
+/// The invariant D of a pool, solved with Newton's method from D = S
+pub fn compute_d(balances: &[FPDecimal], amp: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_positive(balances)?;
+    let ann = amp_times_n_pow_n(amp, balances.len())?;
+    let n = count(balances.len());
+    let sum = balances.iter().try_fold(FPDecimal::zero(), |acc, &balance| acc.checked_add(balance))?;
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        let d_p = d_product(balances, d)?;
+        let numerator = ann.checked_mul(sum)?.checked_add(d_p.checked_mul(n)?)?.checked_mul(d)?;
+        let denominator = ann
+            .checked_sub(FPDecimal::ONE)?
+            .checked_mul(d)?
+            .checked_add(n.checked_add(FPDecimal::ONE)?.checked_mul(d_p)?)?;
+        let previous = d;
+        d = numerator.checked_div(denominator)?;
+        if d.abs_diff(&previous) <= unit() {
+            return Ok(d);
+        }
+    }
+    Err(StdError::generic_err("Stableswap invariant did not converge"))
+}
+
+/// Balance of token `index_out` that keeps the invariant at `d` once token `index_in` holds `balance_in`,
+/// solved with Newton's method on y^2 + (b - D) * y = c
+pub fn compute_y(
+    balances: &[FPDecimal],
+    amp: FPDecimal,
+    d: FPDecimal,
+    index_in: usize,
+    balance_in: FPDecimal,
+    index_out: usize,
+) -> StdResult<FPDecimal> {
+    ensure_pair(balances, index_in, index_out)?;
+    ensure_positive(&[balance_in, d])?;
+    let ann = amp_times_n_pow_n(amp, balances.len())?;
+    let n = count(balances.len());
+
+    let mut c = d;
+    let mut sum = FPDecimal::zero();
+    for (index, &balance) in balances.iter().enumerate() {
+        if index == index_out {
+            continue;
+        }
+        let balance = if index == index_in { balance_in } else { balance };
+        ensure_positive(&[balance])?;
+        sum = sum.checked_add(balance)?;
+        c = c.checked_mul(d)?.checked_div(balance.checked_mul(n)?)?;
+    }
+    c = c.checked_mul(d)?.checked_div(ann.checked_mul(n)?)?;
+    let b = sum.checked_add(d.checked_div(ann)?)?;
+
+    let mut y = d;
+    for _ in 0..MAX_ITERATIONS {
+        let previous = y;
+        let numerator = y.checked_mul(y)?.checked_add(c)?;
+        let denominator = FPDecimal::TWO.checked_mul(y)?.checked_add(b)?.checked_sub(d)?;
+        y = numerator.checked_div(denominator)?;
+        if y.abs_diff(&previous) <= unit() {
+            return Ok(y);
+        }
+    }
+    Err(StdError::generic_err("Stableswap balance did not converge"))
+}
+
+/// Amount of token `index_out` received for exactly `amount_in` of token `index_in`, with `fee` taken from the output.
+/// The result is lowered by one unit so that the solver's rounding never favours the trader.
+pub fn swap_out(
+    balances: &[FPDecimal],
+    amp: FPDecimal,
+    index_in: usize,
+    index_out: usize,
+    amount_in: FPDecimal,
+    fee: FPDecimal,
+) -> StdResult<FPDecimal> {
+    ensure_pair(balances, index_in, index_out)?;
+    ensure_non_negative(amount_in)?;
+    let d = compute_d(balances, amp)?;
+    let balance_in = balances[index_in].checked_add(amount_in)?;
+    let balance_out = compute_y(balances, amp, d, index_in, balance_in, index_out)?;
+    let amount_out = balances[index_out].checked_sub(balance_out)?.checked_sub(unit())?;
+    if amount_out.is_negative() {
+        return Ok(FPDecimal::zero());
+    }
+    Ok(amount_out.checked_mul(after_fee(fee)?)?)
+}
+
+/// Amount of token `index_in` required to receive exactly `amount_out` of token `index_out`, fee included.
+/// The result is raised by one unit so that the solver's rounding never favours the trader.
+pub fn swap_in(
+    balances: &[FPDecimal],
+    amp: FPDecimal,
+    index_in: usize,
+    index_out: usize,
+    amount_out: FPDecimal,
+    fee: FPDecimal,
+) -> StdResult<FPDecimal> {
+    ensure_pair(balances, index_in, index_out)?;
+    ensure_non_negative(amount_out)?;
+    let gross_out = amount_out.checked_div_round(after_fee(fee)?, RoundingMode::Ceil)?;
+    if gross_out >= balances[index_out] {
+        return Err(StdError::generic_err(format!(
+            "Cannot take {gross_out} out of a balance of {}",
+            balances[index_out]
+        )));
+    }
+    let d = compute_d(balances, amp)?;
+    let balance_out = balances[index_out].checked_sub(gross_out)?;
+    let balance_in = compute_y(balances, amp, d, index_out, balance_out, index_in)?;
+    Ok(balance_in.checked_sub(balances[index_in])?.checked_add(unit())?)
+}
+
+/// Marginal price of token `index_in` in units of token `index_out`, the ratio of the invariant's partial derivatives
+/// (A * n^n + D_P / x_in) / (A * n^n + D_P / x_out) with D_P = D^(n + 1) / (n^n * prod(x_i))
+pub fn spot_price(balances: &[FPDecimal], amp: FPDecimal, index_in: usize, index_out: usize) -> StdResult<FPDecimal> {
+    ensure_pair(balances, index_in, index_out)?;
+    let ann = amp_times_n_pow_n(amp, balances.len())?;
+    let d_p = d_product(balances, compute_d(balances, amp)?)?;
+    let marginal_in = ann.checked_add(d_p.checked_div(balances[index_in])?)?;
+    let marginal_out = ann.checked_add(d_p.checked_div(balances[index_out])?)?;
+    marginal_in.checked_div(marginal_out)
+}
+
+/// Relative shortfall of the execution price of a fee-less swap of `amount_in` against the spot price
+pub fn price_impact(balances: &[FPDecimal], amp: FPDecimal, index_in: usize, index_out: usize, amount_in: FPDecimal) -> StdResult<FPDecimal> {
+    let amount_out = swap_out(balances, amp, index_in, index_out, amount_in, FPDecimal::zero())?;
+    impact(spot_price(balances, amp, index_in, index_out)?, amount_in, amount_out)
+}
+
+/// Shares minted for depositing any mix of `deposits`, in proportion to the growth of D.
+/// The first deposit into an empty pool mints D itself.
+pub fn mint(total_supply: FPDecimal, balances: &[FPDecimal], deposits: &[FPDecimal], amp: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_matching(balances, deposits)?;
+    let new_balances = balances
+        .iter()
+        .zip(deposits)
+        .map(|(&balance, &deposit)| balance.checked_add(deposit))
+        .collect::<Result<Vec<_>, _>>()?;
+    let new_d = compute_d(&new_balances, amp)?;
+    if total_supply.is_zero() {
+        return Ok(new_d);
+    }
+
+    let d = compute_d(balances, amp)?;
+    total_supply.checked_mul(new_d.checked_sub(d)?)?.checked_div(d)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amm::stableswap::*;
+    use crate::test_utils::{assert_close, dec, decs};
+    use crate::FPDecimal;
+
+    #[test]
+    fn invariant() {
+        // a balanced pool has D = S for any amplification
+        assert_close(compute_d(&decs(&["1000", "1000", "1000"]), dec("50")).unwrap(), "3000");
+        let balances = decs(&["1000", "3000"]);
+        let d = compute_d(&balances, dec("10")).unwrap();
+        assert_close(d, "3968.969913726304458402");
+        assert_close(compute_y(&balances, dec("10"), d, 0, dec("1100"), 1).unwrap(), "2892.557102430905953393");
+
+        assert!(compute_d(&balances, FPDecimal::zero()).is_err());
+        assert!(compute_y(&balances, dec("10"), d, 0, dec("1100"), 0).is_err());
+    }
+
+    #[test]
+    fn swaps() {
+        let balances = decs(&["1000", "3000"]);
+        let (amp, fee) = (dec("10"), dec("0.0004"));
+        let out = swap_out(&balances, amp, 0, 1, dec("100"), fee).unwrap();
+        assert_close(out, "107.399920410066408988");
+        let amount_in = swap_in(&balances, amp, 0, 1, out, fee).unwrap();
+        assert_close(amount_in, "100");
+        assert!(swap_in(&balances, amp, 0, 1, dec("3000"), fee).is_err());
+
+        // 1 / 0.997 = 1.003009027081243731193..., the gross output including the fee is rounded up
+        assert_eq!(
+            swap_in(&balances, amp, 0, 1, FPDecimal::ONE, dec("0.003")).unwrap(),
+            swap_in(&balances, amp, 0, 1, dec("1.003009027081243732"), FPDecimal::zero()).unwrap()
+        );
+
+        // a balanced, highly amplified pool trades close to 1:1
+        let balanced = decs(&["1000000", "1000000"]);
+        assert_close(spot_price(&balanced, dec("100"), 0, 1).unwrap(), "1");
+        let impact = price_impact(&balanced, dec("100"), 0, 1, dec("1000")).unwrap();
+        assert!(impact > FPDecimal::zero() && impact < dec("0.00001"));
+    }
+
+    #[test]
+    fn spot_price_is_the_marginal_rate() {
+        let balances = decs(&["1000", "3000"]);
+        let amp = dec("10");
+        let price = spot_price(&balances, amp, 0, 1).unwrap();
+        assert_close(price, "1.083222844033599684");
+        let tiny = swap_out(&balances, amp, 0, 1, dec("0.000001"), FPDecimal::zero()).unwrap();
+        assert!((tiny / dec("0.000001")).abs_diff(&price) < dec("0.000001"));
+    }
+
+    #[test]
+    fn liquidity() {
+        let balances = decs(&["1000", "3000"]);
+        let amp = dec("10");
+        let d = compute_d(&balances, amp).unwrap();
+        assert_eq!(mint(FPDecimal::zero(), &balances, &decs(&["0", "0"]), amp).unwrap(), d);
+
+        // a deposit in the pool's ratio mints its share of the supply
+        let shares = mint(d, &balances, &decs(&["100", "300"]), amp).unwrap();
+        assert_close(shares, &(d / dec("10")).to_string());
+        // an imbalanced deposit of the same value mints less
+        let imbalanced = mint(d, &balances, &decs(&["0", "400"]), amp).unwrap();
+        assert!(imbalanced < shares);
+        assert!(mint(d, &balances, &decs(&["1"]), amp).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Balancer style weighted pools, prod(b_i ^ w_i) = k. Weights only matter relative to each other.
+use cosmwasm_std::{StdError, StdResult};
+
+use bigint::U256;
+
+use crate::amm::{after_fee, ensure_matching, ensure_non_negative, ensure_positive, impact, unit};
+use crate::fp_decimal::{FPDecimal, RoundingMode};
+
+// base^exp raised above the error of `powf`: one unit for integer exponents, 1e-15 relative plus one unit otherwise
+fn powf_rounded_up(base: FPDecimal, exp: FPDecimal) -> StdResult<FPDecimal> {
+    let power = base.powf(exp)?;
+    let margin = if exp.is_int() {
+        unit()
+    } else {
+        power.checked_mul(FPDecimal::from(U256::from(1000u64)))?.checked_add(unit())?
+    };
+    Ok(power.checked_add(margin)?)
+}
+
+/// Amount of the output token received for exactly `amount_in`, with `fee` taken from the input:
+/// b_out * (1 - (b_in / (b_in + a_in)) ^ (w_in / w_out)), rounded down past the error of `powf`
+pub fn swap_out(
+    balance_in: FPDecimal,
+    weight_in: FPDecimal,
+    balance_out: FPDecimal,
+    weight_out: FPDecimal,
+    amount_in: FPDecimal,
+    fee: FPDecimal,
+) -> StdResult<FPDecimal> {
+    ensure_positive(&[balance_in, weight_in, balance_out, weight_out])?;
+    ensure_non_negative(amount_in)?;
+    let amount_in = amount_in.checked_mul(after_fee(fee)?)?;
+    let base = balance_in.checked_div_round(balance_in.checked_add(amount_in)?, RoundingMode::Ceil)?;
+    let remaining = powf_rounded_up(base, weight_in.checked_div(weight_out)?)?.min(FPDecimal::ONE);
+    Ok(balance_out.checked_mul(FPDecimal::ONE.checked_sub(remaining)?)?)
+}
+
+/// Amount of the input token, fee included, required to receive exactly `amount_out`:
+/// b_in * ((b_out / (b_out - a_out)) ^ (w_out / w_in) - 1) / (1 - fee), rounded up past the error of `powf`
+pub fn swap_in(
+    balance_in: FPDecimal,
+    weight_in: FPDecimal,
+    balance_out: FPDecimal,
+    weight_out: FPDecimal,
+    amount_out: FPDecimal,
+    fee: FPDecimal,
+) -> StdResult<FPDecimal> {
+    ensure_positive(&[balance_in, weight_in, balance_out, weight_out])?;
+    ensure_non_negative(amount_out)?;
+    if amount_out >= balance_out {
+        return Err(StdError::generic_err(format!(
+            "Cannot take {amount_out} out of a balance of {balance_out}"
+        )));
+    }
+    let base = balance_out.checked_div_round(balance_out.checked_sub(amount_out)?, RoundingMode::Ceil)?;
+    let growth = powf_rounded_up(base, weight_out.checked_div(weight_in)?)?;
+    let amount_in = balance_in.checked_mul_round(growth.checked_sub(FPDecimal::ONE)?, RoundingMode::Ceil)?;
+    amount_in.checked_div_round(after_fee(fee)?, RoundingMode::Ceil)
+}
+
+/// Marginal price of the input token in units of the output token, (b_out / w_out) / (b_in / w_in)
+pub fn spot_price(balance_in: FPDecimal, weight_in: FPDecimal, balance_out: FPDecimal, weight_out: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_positive(&[balance_in, weight_in, balance_out, weight_out])?;
+    balance_out.checked_mul(weight_in)?.checked_div(balance_in.checked_mul(weight_out)?)
+}
+
+/// Relative shortfall of the execution price of a fee-less swap of `amount_in` against the spot price
+pub fn price_impact(
+    balance_in: FPDecimal,
+    weight_in: FPDecimal,
+    balance_out: FPDecimal,
+    weight_out: FPDecimal,
+    amount_in: FPDecimal,
+) -> StdResult<FPDecimal> {
+    let amount_out = swap_out(balance_in, weight_in, balance_out, weight_out, amount_in, FPDecimal::zero())?;
+    impact(spot_price(balance_in, weight_in, balance_out, weight_out)?, amount_in, amount_out)
+}
+
+/// The pool invariant prod(b_i ^ (w_i / sum(w))), also the share supply minted for the first deposit
+pub fn invariant(balances: &[FPDecimal], weights: &[FPDecimal]) -> StdResult<FPDecimal> {
+    ensure_matching(balances, weights)?;
+    ensure_positive(balances)?;
+    ensure_positive(weights)?;
+    let total_weight = weights.iter().try_fold(FPDecimal::zero(), |acc, &weight| acc.checked_add(weight))?;
+    balances.iter().zip(weights).try_fold(FPDecimal::ONE, |acc, (&balance, &weight)| {
+        let factor = balance.powf(weight.checked_div(total_weight)?)?;
+        Ok(acc.checked_mul(factor)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amm::{constant_product, weighted::*};
+    use crate::test_utils::{assert_close, assert_close_within, dec};
+    use crate::FPDecimal;
+
+    #[test]
+    fn equal_weights_match_constant_product() {
+        let (weight, fee) = (dec("0.5"), dec("0.003"));
+        let expected = constant_product::swap_out(dec("1000"), dec("2000"), dec("100"), fee).unwrap();
+        assert_close(
+            swap_out(dec("1000"), weight, dec("2000"), weight, dec("100"), fee).unwrap(),
+            &expected.to_string(),
+        );
+        assert_eq!(spot_price(dec("1000"), weight, dec("2000"), weight).unwrap(), FPDecimal::TWO);
+    }
+
+    #[test]
+    fn swaps_round_against_the_trader() {
+        let weight = dec("0.5");
+        // exact value 2000 / 1001 = 1.998001998001998001998...
+        let out = swap_out(dec("1000"), weight, dec("2000"), weight, dec("1"), FPDecimal::zero()).unwrap();
+        assert!(out <= dec("1.998001998001998001"), "{}", out);
+        assert_close(out, "1.998001998001998001");
+
+        // exact value 1000 / 1999 = 0.500250125062531265632...
+        let amount_in = swap_in(dec("1000"), weight, dec("2000"), weight, dec("1"), FPDecimal::zero()).unwrap();
+        assert!(amount_in >= dec("0.500250125062531266"), "{}", amount_in);
+        assert_close(amount_in, "0.500250125062531266");
+
+        // the 80/20 references below bound the results from the pool's side
+        let (w_in, w_out) = (dec("0.8"), dec("0.2"));
+        let out = swap_out(dec("1000"), w_in, dec("500"), w_out, dec("10"), FPDecimal::zero()).unwrap();
+        assert!(out <= dec("19.509827758591858587"), "{}", out);
+        let amount_in = swap_in(dec("1000"), w_in, dec("500"), w_out, dec("19.509827758591858587"), FPDecimal::zero()).unwrap();
+        assert!(amount_in >= dec("10"), "{}", amount_in);
+    }
+
+    #[test]
+    fn uneven_weights() {
+        // 80/20 pool
+        let (w_in, w_out) = (dec("0.8"), dec("0.2"));
+        let out = swap_out(dec("1000"), w_in, dec("500"), w_out, dec("10"), FPDecimal::zero()).unwrap();
+        assert_close(out, "19.509827758591858587");
+        // both directions carry the margin for the error of `powf`, so the round trip overshoots slightly
+        assert_close_within(
+            swap_in(dec("1000"), w_in, dec("500"), w_out, out, FPDecimal::zero()).unwrap(),
+            "10",
+            "0.000000000002",
+        );
+        assert_eq!(spot_price(dec("1000"), w_in, dec("500"), w_out).unwrap(), FPDecimal::TWO);
+        assert_close(
+            price_impact(dec("1000"), w_in, dec("500"), w_out, dec("10")).unwrap(),
+            "0.024508612070407070",
+        );
+
+        // 1000^0.8 * 500^0.2
+        assert_close(invariant(&[dec("1000"), dec("500")], &[w_in, w_out]).unwrap(), "870.550563296124139136");
+        assert!(invariant(&[dec("1000")], &[w_in, w_out]).is_err());
+        assert!(swap_in(dec("1000"), w_in, dec("500"), w_out, dec("500"), FPDecimal::zero()).is_err());
+    }
+}
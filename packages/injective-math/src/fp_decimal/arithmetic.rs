@@ -1,6 +1,6 @@
 /// Arithmetic operators for FPDecimal
 use crate::fp_decimal::{FPDecimal, U256};
-use std::ops;
+use std::{iter, ops};
 
 impl FPDecimal {
     pub fn _add(x: FPDecimal, y: FPDecimal) -> FPDecimal {
@@ -41,28 +41,10 @@ impl FPDecimal {
         FPDecimal::_sub(*self, FPDecimal::from(other))
     }
 
+    /// Multiplies through a 512-bit intermediate and rescales once, so the product keeps all 18 decimals
     pub fn _mul(x: FPDecimal, y: FPDecimal) -> FPDecimal {
-        let mut sign = 1;
-        if x.sign != y.sign {
-            sign = 0;
-        }
-        let x1: U256 = FPDecimal::_int(x).num / FPDecimal::ONE.num;
-        let mut x2: U256 = FPDecimal::_fraction(x).num;
-        let y1: U256 = FPDecimal::_int(y).num / FPDecimal::ONE.num;
-        let mut y2: U256 = FPDecimal::_fraction(y).num;
-        let mut x1y1 = x1 * y1;
-        let dec_x1y1 = x1y1 * FPDecimal::ONE.num;
-        x1y1 = dec_x1y1;
-        let x2y1 = x2 * y1;
-        let x1y2 = x1 * y2;
-        x2 = x2 / FPDecimal::MUL_PRECISION.num;
-        y2 = y2 / FPDecimal::MUL_PRECISION.num;
-        let x2y2 = x2 * y2;
-        let mut result = x1y1;
-        result = result + x2y1;
-        result = result + x1y2;
-        result = result + x2y2;
-        FPDecimal { num: result, sign }
+        x.checked_mul(y)
+            .unwrap_or_else(|err| panic!("FPDecimal multiplication overflow: {}", err))
     }
 
     pub fn mul(&self, other: i128) -> FPDecimal {
@@ -162,11 +144,77 @@ impl ops::Div for FPDecimal {
     }
 }
 
+impl ops::DivAssign for FPDecimal {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = FPDecimal::_div(*self, rhs);
+    }
+}
+
+impl ops::Neg for FPDecimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            return FPDecimal::zero();
+        }
+        FPDecimal {
+            num: self.num,
+            sign: 1 - self.sign,
+        }
+    }
+}
+
+/// Remainder of the division truncated toward zero, with the sign of the dividend like `%` on integers
+impl ops::Rem for FPDecimal {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "Division by zero");
+        let num = self.num % rhs.num;
+        if num.is_zero() {
+            return FPDecimal::zero();
+        }
+        FPDecimal { num, sign: self.sign }
+    }
+}
+
+impl ops::RemAssign for FPDecimal {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl iter::Sum for FPDecimal {
+    fn sum<I: Iterator<Item = FPDecimal>>(iter: I) -> Self {
+        iter.fold(FPDecimal::zero(), FPDecimal::_add)
+    }
+}
+
+impl<'a> iter::Sum<&'a FPDecimal> for FPDecimal {
+    fn sum<I: Iterator<Item = &'a FPDecimal>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl iter::Product for FPDecimal {
+    fn product<I: Iterator<Item = FPDecimal>>(iter: I) -> Self {
+        iter.fold(FPDecimal::ONE, FPDecimal::_mul)
+    }
+}
+
+impl<'a> iter::Product<&'a FPDecimal> for FPDecimal {
+    fn product<I: Iterator<Item = &'a FPDecimal>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::FPDecimal;
     use bigint::U256;
+    use num::{pow::Pow, BigInt, BigRational};
+    use std::str::FromStr;
 
     #[test]
     fn test_into_u128() {
@@ -511,4 +559,101 @@ mod tests {
         let ans = lhs.abs_diff(&rhs);
         assert_eq!(FPDecimal::from(3u128), ans);
     }
+
+    #[test]
+    fn test_mul_keeps_small_digits() {
+        let small = FPDecimal::from_str("0.000000001234").unwrap();
+        let nano = FPDecimal::from_str("0.000000001").unwrap();
+        assert_eq!(small * nano, FPDecimal::from_str("0.000000000000000001").unwrap());
+
+        let lhs = FPDecimal::from_str("0.123456789123456789").unwrap();
+        let rhs = FPDecimal::from_str("0.987654321987654321").unwrap();
+        assert_eq!(lhs * rhs, FPDecimal::from_str("0.121932631356500531").unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn panic_mul_overflow() {
+        let _ = FPDecimal::MAX * FPDecimal::TWO;
+    }
+
+    #[test]
+    fn test_neg() {
+        let value = FPDecimal::from_str("1.5").unwrap();
+        assert_eq!(-value, FPDecimal::from_str("-1.5").unwrap());
+        assert_eq!(-(-value), value);
+        assert_eq!(-FPDecimal::zero(), FPDecimal::zero());
+        assert!(!(-FPDecimal::zero()).is_negative());
+    }
+
+    #[test]
+    fn test_rem() {
+        let value = |s: &str| FPDecimal::from_str(s).unwrap();
+        assert_eq!(value("7.5") % value("2"), value("1.5"));
+        assert_eq!(value("-7.5") % value("2"), value("-1.5"));
+        assert_eq!(value("7.5") % value("-2"), value("1.5"));
+        assert_eq!(value("6") % value("1.5"), FPDecimal::zero());
+
+        let mut remainder = value("10");
+        remainder %= value("3");
+        assert_eq!(remainder, FPDecimal::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn panic_rem_zero() {
+        let _ = FPDecimal::ONE % FPDecimal::zero();
+    }
+
+    #[test]
+    fn test_div_assign() {
+        let mut value = FPDecimal::from(9u128);
+        value /= FPDecimal::from(2u128);
+        assert_eq!(value, FPDecimal::from_str("4.5").unwrap());
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let values = vec![FPDecimal::from(2u128), FPDecimal::from(-3i128), FPDecimal::from_str("0.5").unwrap()];
+        assert_eq!(values.iter().sum::<FPDecimal>(), FPDecimal::from_str("-0.5").unwrap());
+        assert_eq!(values.into_iter().product::<FPDecimal>(), FPDecimal::from(-3i128));
+        assert_eq!(Vec::<FPDecimal>::new().into_iter().sum::<FPDecimal>(), FPDecimal::zero());
+        assert_eq!(Vec::<FPDecimal>::new().iter().product::<FPDecimal>(), FPDecimal::ONE);
+    }
+
+    // xorshift64, so the property test is reproducible without extra dependencies
+    fn next_random(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn random_decimal(state: &mut u64) -> FPDecimal {
+        let magnitude = ((next_random(state) as u128) << 64 | next_random(state) as u128) >> (next_random(state) % 128);
+        FPDecimal {
+            num: U256::from_little_endian(&magnitude.to_le_bytes()),
+            sign: if magnitude == 0 { 1 } else { (next_random(state) % 2) as i8 },
+        }
+    }
+
+    fn to_rational(value: FPDecimal) -> BigRational {
+        let num = BigInt::from_str(&value.num.to_string()).unwrap();
+        let num = if value.is_negative() { -num } else { num };
+        BigRational::new(num, BigInt::from(10u64).pow(18u32))
+    }
+
+    #[test]
+    fn test_mul_matches_rational_reference() {
+        let scale = BigRational::from_integer(BigInt::from(10u64).pow(18u32));
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..1000 {
+            let lhs = random_decimal(&mut state);
+            let rhs = random_decimal(&mut state);
+            // FPDecimal truncates towards zero at the 18th decimal
+            let expected = (to_rational(lhs) * to_rational(rhs) * scale.clone()).trunc();
+            let actual = to_rational(lhs * rhs) * scale.clone();
+            assert_eq!(actual, expected, "{lhs} * {rhs}");
+        }
+    }
 }
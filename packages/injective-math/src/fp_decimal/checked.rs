@@ -0,0 +1,195 @@
+/// Checked and saturating arithmetic for FPDecimal, for callers that must not panic
+use bigint::U512;
+use cosmwasm_std::{DivideByZeroError, OverflowError, OverflowOperation, StdError, StdResult, Uint128};
+
+use crate::fp_decimal::{FPDecimal, U256};
+
+impl FPDecimal {
+    pub fn checked_add(self, other: FPDecimal) -> Result<FPDecimal, OverflowError> {
+        if self.sign == other.sign {
+            let (num, overflow) = self.num.overflowing_add(other.num);
+            if overflow {
+                return Err(OverflowError::new(OverflowOperation::Add, self, other));
+            }
+            return Ok(FPDecimal { num, sign: self.sign });
+        }
+        // operands of opposite signs cannot overflow
+        Ok(FPDecimal::_add(self, other))
+    }
+
+    pub fn checked_sub(self, other: FPDecimal) -> Result<FPDecimal, OverflowError> {
+        self.checked_add(FPDecimal::signed(other.num, 1 ^ other.sign))
+            .map_err(|_| OverflowError::new(OverflowOperation::Sub, self, other))
+    }
+
+    /// Multiplies with a 512-bit intermediate product, so no precision is lost before the final rescale
+    pub fn checked_mul(self, other: FPDecimal) -> Result<FPDecimal, OverflowError> {
+        let num = self.num.full_mul(other.num) / U512::from(FPDecimal::ONE.num);
+        let num = u512_to_u256(num).ok_or_else(|| OverflowError::new(OverflowOperation::Mul, self, other))?;
+        Ok(FPDecimal::signed(num, 1 ^ self.sign ^ other.sign))
+    }
+
+    /// Fails with `StdError::DivideByZero`, or with the error of `division_overflow` when the quotient does not fit
+    pub fn checked_div(self, other: FPDecimal) -> StdResult<FPDecimal> {
+        if other.is_zero() {
+            return Err(DivideByZeroError::new(self).into());
+        }
+        let num = FPDecimal::ONE.num.full_mul(self.num) / U512::from(other.num);
+        let num = u512_to_u256(num).ok_or_else(|| division_overflow(self, other))?;
+        Ok(FPDecimal::signed(num, 1 ^ self.sign ^ other.sign))
+    }
+
+    pub fn checked_reciprocal(self) -> Result<FPDecimal, DivideByZeroError> {
+        if self.is_zero() {
+            return Err(DivideByZeroError::new(self));
+        }
+        Ok(FPDecimal::reciprocal(self))
+    }
+
+    pub fn checked_ln(self) -> StdResult<FPDecimal> {
+        if self.is_negative() || self.is_zero() {
+            return Err(StdError::generic_err(format!("ln is undefined for {self}")));
+        }
+        Ok(FPDecimal::_ln(self))
+    }
+
+    /// e^self, failing instead of panicking once the result exceeds `FPDecimal::MAX` (exponents above about 135.999)
+    pub fn checked_exp(self) -> StdResult<FPDecimal> {
+        if self > MAX_EXPONENT {
+            return Err(OverflowError::new(OverflowOperation::Pow, FPDecimal::E, self).into());
+        }
+        Ok(FPDecimal::_exp(self))
+    }
+
+    pub fn checked_sqrt(self) -> StdResult<FPDecimal> {
+        FPDecimal::sqrt(self).ok_or_else(|| StdError::generic_err(format!("sqrt is undefined for {self}")))
+    }
+
+    /// Integer part as `u128`, failing on negative values and on overflow instead of panicking like `u128::from`.
+    /// These checked conversions stand in for `TryFrom`, which the existing `From` impls rule out.
+    pub fn checked_to_u128(self) -> StdResult<u128> {
+        if self.is_negative() && !self.int().is_zero() {
+            return Err(StdError::generic_err(format!("Cannot convert negative {self} to u128")));
+        }
+        let num = self.num / FPDecimal::ONE.num;
+        if num.bits() > 128 {
+            return Err(StdError::generic_err(format!("{self} overflows u128")));
+        }
+        Ok(num.low_u64() as u128 | ((num >> 64).low_u64() as u128) << 64)
+    }
+
+    pub fn checked_to_uint128(self) -> StdResult<Uint128> {
+        self.checked_to_u128().map(Uint128::from)
+    }
+
+    pub fn saturating_add(self, other: FPDecimal) -> FPDecimal {
+        self.checked_add(other).unwrap_or_else(|_| FPDecimal::saturated(self.sign))
+    }
+
+    pub fn saturating_sub(self, other: FPDecimal) -> FPDecimal {
+        self.checked_sub(other).unwrap_or_else(|_| FPDecimal::saturated(self.sign))
+    }
+
+    pub fn saturating_mul(self, other: FPDecimal) -> FPDecimal {
+        self.checked_mul(other)
+            .unwrap_or_else(|_| FPDecimal::saturated(1 ^ self.sign ^ other.sign))
+    }
+
+    /// Division by zero saturates towards the sign of the dividend, like an infinite quotient would
+    pub fn saturating_div(self, other: FPDecimal) -> FPDecimal {
+        self.checked_div(other)
+            .unwrap_or_else(|_| FPDecimal::saturated(1 ^ self.sign ^ other.sign))
+    }
+
+    fn saturated(sign: i8) -> FPDecimal {
+        if sign == 0 {
+            FPDecimal::MIN
+        } else {
+            FPDecimal::MAX
+        }
+    }
+
+    // zero is always positive
+    fn signed(num: U256, sign: i8) -> FPDecimal {
+        FPDecimal {
+            num,
+            sign: if num.is_zero() { 1 } else { sign },
+        }
+    }
+}
+
+// 135.999, just below ln(FPDecimal::MAX) = 135.99914...
+const MAX_EXPONENT: FPDecimal = FPDecimal {
+    num: U256([6871791484033138688, 7, 0, 0]),
+    sign: 1,
+};
+
+// `OverflowOperation` has no division, so every checked division reports its overflow with this error
+pub(super) fn division_overflow(dividend: FPDecimal, divisor: FPDecimal) -> StdError {
+    StdError::generic_err(format!("Dividing {dividend} by {divisor} overflows"))
+}
+
+pub(super) fn u512_to_u256(value: U512) -> Option<U256> {
+    if value > U512::from(U256::MAX) {
+        None
+    } else {
+        Some(U256::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{StdError, Uint128};
+
+    use crate::test_utils::dec;
+    use crate::FPDecimal;
+
+    #[test]
+    fn checked_ops_match_operators() {
+        assert_eq!(dec("1.5").checked_add(dec("-2.25")).unwrap(), dec("-0.75"));
+        assert_eq!(dec("1.5").checked_sub(dec("2.25")).unwrap(), dec("-0.75"));
+        assert_eq!(dec("-1.5").checked_mul(dec("4")).unwrap(), dec("-6"));
+        assert_eq!(dec("-6").checked_div(dec("-4")).unwrap(), dec("1.5"));
+        assert_eq!(dec("0.00000001").checked_mul(dec("0.0000000001")).unwrap(), dec("0.000000000000000001"));
+    }
+
+    #[test]
+    fn checked_ops_report_errors() {
+        assert!(FPDecimal::MAX.checked_add(FPDecimal::ONE).is_err());
+        assert!(FPDecimal::MIN.checked_sub(FPDecimal::ONE).is_err());
+        assert!(FPDecimal::MAX.checked_mul(FPDecimal::TWO).is_err());
+        assert!(matches!(
+            FPDecimal::ONE.checked_div(FPDecimal::zero()),
+            Err(StdError::DivideByZero { .. })
+        ));
+        assert_eq!(
+            FPDecimal::MAX.checked_div(dec("0.5")).unwrap_err(),
+            StdError::generic_err(format!("Dividing {} by 0.5 overflows", FPDecimal::MAX))
+        );
+        assert!(FPDecimal::zero().checked_reciprocal().is_err());
+        assert!(FPDecimal::zero().checked_ln().is_err());
+        assert!(matches!(dec("136").checked_exp(), Err(StdError::Overflow { .. })));
+        assert!(dec("135.999").checked_exp().unwrap() > FPDecimal::MAX / FPDecimal::TWO);
+        assert_eq!(dec("-100").checked_exp().unwrap(), FPDecimal::zero());
+        assert!(dec("-4").checked_sqrt().is_err());
+        assert_eq!(dec("4").checked_sqrt().unwrap(), FPDecimal::TWO);
+    }
+
+    #[test]
+    fn checked_conversions() {
+        assert_eq!(dec("10.9").checked_to_u128().unwrap(), 10u128);
+        assert_eq!(FPDecimal::from(u128::MAX).checked_to_uint128().unwrap(), Uint128::MAX);
+        assert_eq!(dec("-0.5").checked_to_u128().unwrap(), 0u128);
+        assert!(dec("-1").checked_to_u128().is_err());
+        assert!((FPDecimal::from(u128::MAX) + FPDecimal::ONE).checked_to_u128().is_err());
+    }
+
+    #[test]
+    fn saturating_ops() {
+        assert_eq!(FPDecimal::MAX.saturating_add(FPDecimal::ONE), FPDecimal::MAX);
+        assert_eq!(FPDecimal::MIN.saturating_sub(FPDecimal::ONE), FPDecimal::MIN);
+        assert_eq!(FPDecimal::MAX.saturating_mul(dec("-2")), FPDecimal::MIN);
+        assert_eq!(dec("-1").saturating_div(FPDecimal::zero()), FPDecimal::MIN);
+        assert_eq!(dec("3").saturating_div(dec("2")), dec("1.5"));
+    }
+}
@@ -1,5 +1,5 @@
-use cosmwasm_std::{OverflowError, OverflowOperation};
-use std::str::FromStr;
+use cosmwasm_std::{OverflowError, OverflowOperation, StdError, StdResult};
+use std::convert::TryFrom;
 
 /// Exponential functions for FPDecimal
 use crate::fp_decimal::{FPDecimal, U256};
@@ -79,285 +79,130 @@ impl FPDecimal {
         Some(r)
     }
 
-    pub fn checked_pow(self, rhs: FPDecimal) -> Result<FPDecimal, OverflowError> {
-        {
-            // This uses the exponentiation by squaring algorithm:
-            // https://en.wikipedia.org/wiki/Exponentiation_by_squaring#Basic_method
+    /// a^b for any real exponent, the same as `powf`: integer exponents go through `checked_powi`.
+    /// Overflows fail with `StdError::Overflow`, results that are not real numbers such as (-2)^0.5 or 0^-1
+    /// with `StdError::GenericErr`.
+    pub fn checked_pow(self, rhs: FPDecimal) -> StdResult<FPDecimal> {
+        self.powf(rhs)
+    }
 
-            if self == FPDecimal::zero() {
-                return Ok(FPDecimal::zero());
-            }
-            if self > FPDecimal::zero() && rhs == FPDecimal::zero() {
-                return Ok(FPDecimal::one());
+    /// 10^exp, exact for -18 <= exp <= 59. Smaller exponents underflow to zero, larger ones overflow.
+    pub fn pow10(exp: i32) -> FPDecimal {
+        FPDecimal::checked_pow10(exp).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn checked_pow10(exp: i32) -> Result<FPDecimal, OverflowError> {
+        // U256::MAX is about 1.16e77
+        const MAX_DIGITS: i64 = 77;
+        let digits = FPDecimal::DIGITS as i64 + exp as i64;
+        if digits < 0 {
+            return Ok(FPDecimal::zero());
+        }
+        if digits > MAX_DIGITS {
+            return Err(OverflowError::new(OverflowOperation::Pow, 10, exp));
+        }
+        Ok(FPDecimal {
+            num: U256::exp10(digits as usize),
+            sign: 1,
+        })
+    }
+
+    /// a^n by exponentiation by squaring. Every step truncates at 18 decimals, so the relative error grows
+    /// roughly linearly with log2(n); negative exponents take the reciprocal of the positive power.
+    pub fn powi(self, exp: i64) -> FPDecimal {
+        self.checked_powi(exp).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn checked_powi(self, exp: i64) -> Result<FPDecimal, OverflowError> {
+        let overflow = || OverflowError::new(OverflowOperation::Pow, self, exp);
+        if self == FPDecimal::from(10u128) {
+            return FPDecimal::checked_pow10(i32::try_from(exp).unwrap_or(if exp < 0 { i32::MIN } else { i32::MAX })).map_err(|_| overflow());
+        }
+
+        let mut base = self;
+        let mut n = exp.unsigned_abs();
+        let mut result = FPDecimal::ONE;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.checked_mul(base).map_err(|_| overflow())?;
             }
-            if self.is_negative() && rhs == FPDecimal::zero() {
-                return Ok(FPDecimal::NEGATIVE_ONE);
+            n >>= 1;
+            if n > 0 {
+                base = base.checked_mul(base).map_err(|_| overflow())?;
             }
+        }
 
-            if self == FPDecimal::from(10u128) {
-                if rhs == FPDecimal::one() {
-                    return Ok(FPDecimal::from(10u128));
-                }
-                if rhs == FPDecimal::TWO {
-                    return Ok(FPDecimal::from(100u128));
-                }
-                if rhs == FPDecimal::THREE {
-                    return Ok(FPDecimal::from(1000u128));
-                }
-                if rhs == FPDecimal::FOUR {
-                    return Ok(FPDecimal::from(10000u128));
-                }
-                if rhs == FPDecimal::FIVE {
-                    return Ok(FPDecimal::from(100000u128));
-                }
-                if rhs == FPDecimal::SIX {
-                    return Ok(FPDecimal::from(1000000u128));
-                }
-                if rhs == FPDecimal::SEVEN {
-                    return Ok(FPDecimal::from(10000000u128));
-                }
-                if rhs == FPDecimal::EIGHT {
-                    return Ok(FPDecimal::from(100000000u128));
-                }
-                if rhs == FPDecimal::NINE {
-                    return Ok(FPDecimal::from(1000000000u128));
-                }
-                if rhs == FPDecimal::from(10u128) {
-                    return Ok(FPDecimal::from(10000000000u128));
-                }
-                if rhs == FPDecimal::from(11u128) {
-                    return Ok(FPDecimal::from(100000000000u128));
-                }
-                if rhs == FPDecimal::from(12u128) {
-                    return Ok(FPDecimal::from(1000000000000u128));
-                }
-                if rhs == FPDecimal::from(13u128) {
-                    return Ok(FPDecimal::from(10000000000000u128));
-                }
-                if rhs == FPDecimal::from(14u128) {
-                    return Ok(FPDecimal::from(100000000000000u128));
-                }
-                if rhs == FPDecimal::from(15u128) {
-                    return Ok(FPDecimal::from(1000000000000000u128));
-                }
-                if rhs == FPDecimal::from(16u128) {
-                    return Ok(FPDecimal::from(10000000000000000u128));
-                }
-                if rhs == FPDecimal::from(17u128) {
-                    return Ok(FPDecimal::from(100000000000000000u128));
-                }
-                if rhs == FPDecimal::from(18u128) {
-                    return Ok(FPDecimal::from(1000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(19u128) {
-                    return Ok(FPDecimal::from(10000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(20u128) {
-                    return Ok(FPDecimal::from(100000000000000000000u128));
-                }
-                if rhs == FPDecimal::NEGATIVE_ONE {
-                    return Ok(FPDecimal::from_str("0.1").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-2").unwrap() {
-                    return Ok(FPDecimal::from_str("0.01").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-3").unwrap() {
-                    return Ok(FPDecimal::from_str("0.001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-4").unwrap() {
-                    return Ok(FPDecimal::from_str("0.0001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-5").unwrap() {
-                    return Ok(FPDecimal::from_str("0.00001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-6").unwrap() {
-                    return Ok(FPDecimal::from_str("0.000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-7").unwrap() {
-                    return Ok(FPDecimal::from_str("0.0000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-8").unwrap() {
-                    return Ok(FPDecimal::from_str("0.00000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-9").unwrap() {
-                    return Ok(FPDecimal::from_str("0.000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-10").unwrap() {
-                    return Ok(FPDecimal::from_str("0.0000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-11").unwrap() {
-                    return Ok(FPDecimal::from_str("0.00000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-12").unwrap() {
-                    return Ok(FPDecimal::from_str("0.000000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-13").unwrap() {
-                    return Ok(FPDecimal::from_str("0.0000000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-14").unwrap() {
-                    return Ok(FPDecimal::from_str("0.00000000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-15").unwrap() {
-                    return Ok(FPDecimal::from_str("0.000000000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-16").unwrap() {
-                    return Ok(FPDecimal::from_str("0.0000000000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-17").unwrap() {
-                    return Ok(FPDecimal::from_str("0.00000000000000001").unwrap());
-                }
-                if rhs == FPDecimal::from_str("-18").unwrap() {
-                    return Ok(FPDecimal::from_str("0.000000000000000001").unwrap());
-                }
-                if rhs < FPDecimal::from_str("-18").unwrap() {
-                    return Ok(FPDecimal::zero());
-                }
-                if rhs == FPDecimal::from(21u128) {
-                    return Ok(FPDecimal::from(1000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(22u128) {
-                    return Ok(FPDecimal::from(10000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(23u128) {
-                    return Ok(FPDecimal::from(100000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(24u128) {
-                    return Ok(FPDecimal::from(1000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(25u128) {
-                    return Ok(FPDecimal::from(10000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(26u128) {
-                    return Ok(FPDecimal::from(100000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(27u128) {
-                    return Ok(FPDecimal::from(1000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(28u128) {
-                    return Ok(FPDecimal::from(10000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(29u128) {
-                    return Ok(FPDecimal::from(100000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(30u128) {
-                    return Ok(FPDecimal::from(1000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(31u128) {
-                    return Ok(FPDecimal::from(10000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(32u128) {
-                    return Ok(FPDecimal::from(100000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(33u128) {
-                    return Ok(FPDecimal::from(1000000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(34u128) {
-                    return Ok(FPDecimal::from(10000000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(35u128) {
-                    return Ok(FPDecimal::from(100000000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(36u128) {
-                    return Ok(FPDecimal::from(1000000000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(37u128) {
-                    return Ok(FPDecimal::from(10000000000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(38u128) {
-                    return Ok(FPDecimal::from(100000000000000000000000000000000000000u128));
-                }
-                if rhs == FPDecimal::from(39u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(40u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(41u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(42u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(43u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(44u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(45u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(46u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(47u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(48u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(49u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(50u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(51u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(52u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(53u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(54u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(55u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(56u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(57u128) {
-                    return Ok(FPDecimal::from_str("1000000000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(58u128) {
-                    return Ok(FPDecimal::from_str("10000000000000000000000000000000000000000000000000000000000").unwrap());
-                }
-                if rhs == FPDecimal::from(59u128) {
-                    return Ok(FPDecimal::from_str("100000000000000000000000000000000000000000000000000000000000").unwrap());
-                }
-            }
+        if exp < 0 {
+            return FPDecimal::ONE.checked_div(result).map_err(|_| overflow());
+        }
+        Ok(result)
+    }
 
-            fn inner(mut x: FPDecimal, mut n: FPDecimal) -> Result<FPDecimal, OverflowError> {
-                if n == FPDecimal::zero() {
-                    return Ok(FPDecimal::one());
-                }
-
-                let mut y = FPDecimal::one();
-
-                while n > FPDecimal::one() {
-                    if n.num % FPDecimal::TWO.num == FPDecimal::zero().num {
-                        x = x * x;
-                        n = n / FPDecimal::TWO;
-                    } else {
-                        y = x * y;
-                        x = x * x;
-                        n = (n - FPDecimal::ONE) / FPDecimal::TWO;
-                    }
-                }
-
-                Ok(x * y)
-            }
+    /// a^b for a real exponent b, computed as a^int(b) * exp(frac(b) * ln(a)) so the transcendental part
+    /// only ever sees a fractional exponent. The relative error stays below 1e-15 for results of at least 1e-3;
+    /// smaller results are limited by the 18th decimal.
+    pub fn powf(self, exp: FPDecimal) -> StdResult<FPDecimal> {
+        if self.is_zero() && exp.is_negative() {
+            return Err(StdError::generic_err(format!("0 cannot be raised to the negative power {exp}")));
+        }
+        if exp.is_int() {
+            let n = exp
+                .abs()
+                .checked_to_u128()
+                .ok()
+                .and_then(|n| i64::try_from(n).ok())
+                .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, self, exp))?;
+            return Ok(self.checked_powi(if exp.is_negative() { -n } else { n })?);
+        }
+        if self.is_zero() {
+            return Ok(FPDecimal::zero());
+        }
+        if self.is_negative() {
+            return Err(StdError::generic_err(format!("{self}^{exp} is not a real number")));
+        }
+
+        let whole = self.powf(exp.int())?;
+        let partial = FPDecimal::_exp(exp.fraction() * self.ln());
+        Ok(whole.checked_mul(partial)?)
+    }
+
+    /// The real n-th root, refined with Newton's method from a `powf` estimate; accurate to the last decimal
+    /// up to truncation. Negative values only have odd roots.
+    pub fn nth_root(self, n: u32) -> StdResult<FPDecimal> {
+        const MAX_ITERATIONS: usize = 100;
+
+        if n == 0 {
+            return Err(StdError::generic_err("The 0th root is undefined"));
+        }
+        if n == 1 || self.is_zero() {
+            return Ok(self);
+        }
+        if self.is_negative() && n % 2 == 0 {
+            return Err(StdError::generic_err(format!("{self} has no real root of even degree {n}")));
+        }
 
-            inner(self, rhs).map_err(|_| OverflowError {
-                operation: OverflowOperation::Pow,
-                operand1: self.to_string(),
-                operand2: rhs.to_string(),
-            })
+        let x = self.abs();
+        let degree = FPDecimal::from(n as u128);
+        let mut root = x.powf(FPDecimal::ONE / degree)?;
+        for _ in 0..MAX_ITERATIONS {
+            let power = root.checked_powi(n as i64 - 1)?;
+            // for tiny inputs of high degree the power truncates to zero, the powf estimate is then all there is
+            if power.is_zero() {
+                break;
+            }
+            let next = ((degree - FPDecimal::ONE) * root + x.checked_div(power)?) / degree;
+            // truncation can leave Newton's method alternating between neighbouring values
+            let settled = next.abs_diff(&root).num <= U256::one();
+            root = next;
+            if settled {
+                break;
+            }
         }
+
+        Ok(FPDecimal {
+            num: root.num,
+            sign: self.sign,
+        })
     }
 }
 
@@ -367,20 +212,20 @@ impl Pow<FPDecimal> for FPDecimal {
     //     Self::_pow(self, rhs)
     // }
 
-    /// Raises a value to the power of `exp`, panics if an overflow occurred.
+    /// Raises a value to the power of `exp`, panics with the error of `checked_pow` on overflow
+    /// and when the result is not a real number.
     fn pow(self, exp: FPDecimal) -> Self {
-        match self.checked_pow(exp) {
-            Ok(value) => value,
-            Err(_) => panic!("Multiplication overflow"),
-        }
+        self.checked_pow(exp).unwrap_or_else(|err| panic!("{}", err))
     }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use crate::test_utils::assert_close_relative;
     use crate::FPDecimal;
     use bigint::U256;
+    use cosmwasm_std::StdError;
     use num::pow::Pow;
     use std::str::FromStr;
 
@@ -430,13 +275,12 @@ mod tests {
 
     #[test]
     fn test_pow_exp10() {
-        assert_eq!(
-            FPDecimal::E.pow(FPDecimal {
-                num: U256([10, 0, 0, 0]) * FPDecimal::ONE.num,
-                sign: 1
-            }),
-            FPDecimal::E_10
-        );
+        // E is truncated to 18 decimals, which compounds to an error of ~3e-14 in E^10
+        let result = FPDecimal::E.pow(FPDecimal {
+            num: U256([10, 0, 0, 0]) * FPDecimal::ONE.num,
+            sign: 1,
+        });
+        assert!(result.abs_diff(&FPDecimal::E_10) < FPDecimal::from_str("0.0000000000001").unwrap());
     }
 
     #[test]
@@ -521,5 +365,131 @@ mod tests {
 
         let result = FPDecimal::checked_pow(base, FPDecimal::from(2u128)).unwrap();
         assert_eq!(result, FPDecimal::from(4u128));
+
+        // integer exponents beyond u128 are an overflow, not a panic
+        let huge = FPDecimal::from_str("10000000000000000000000000000000000000000").unwrap();
+        assert!(base.checked_pow(huge).is_err());
+        assert!(base.checked_pow(-huge).is_err());
+        assert!(base.powf(huge).is_err());
+    }
+
+    #[test]
+    fn test_checked_pow_matches_powf() {
+        let minus_seven = FPDecimal::from_str("-7").unwrap();
+        assert_eq!(minus_seven.pow(FPDecimal::ZERO), FPDecimal::ONE);
+        assert_eq!(
+            minus_seven.checked_pow(FPDecimal::ZERO).unwrap(),
+            minus_seven.powf(FPDecimal::ZERO).unwrap()
+        );
+
+        assert_eq!(FPDecimal::ZERO.pow(FPDecimal::ZERO), FPDecimal::ONE);
+        assert!(FPDecimal::ZERO.checked_pow(FPDecimal::NEGATIVE_ONE).is_err());
+        assert!(FPDecimal::ZERO.powf(FPDecimal::NEGATIVE_ONE).is_err());
+    }
+
+    #[test]
+    fn test_powf_reference_values() {
+        let cases = [
+            ("2", "0.37", "1.292352830637492244"),
+            ("0.5", "0.37", "0.773782496771194952"),
+            ("1234.5678", "2.5", "53553438.871132430115"),
+            ("0.001", "-1.5", "31622.776601683793319988"),
+            ("1.0001", "1000.5", "1.105220649491475186"),
+            ("3", "-0.25", "0.759835685651592547"),
+            ("50", "17.7", "1179693201869933031000000000000"),
+        ];
+        for (base, exp, expected) in cases {
+            let result = FPDecimal::from_str(base).unwrap().powf(FPDecimal::from_str(exp).unwrap()).unwrap();
+            assert_close_relative(result, expected, "0.000000000000001");
+        }
+    }
+
+    #[test]
+    fn test_powf_invalid() {
+        assert!(FPDecimal::from_str("-2").unwrap().powf(FPDecimal::from_str("0.5").unwrap()).is_err());
+        assert!(FPDecimal::zero().powf(FPDecimal::from_str("-0.5").unwrap()).is_err());
+        assert_eq!(FPDecimal::zero().powf(FPDecimal::from_str("0.5").unwrap()).unwrap(), FPDecimal::zero());
+        assert!(FPDecimal::from_str("-2")
+            .unwrap()
+            .checked_pow(FPDecimal::from_str("0.5").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_checked_pow_distinguishes_domain_errors_from_overflow() {
+        let half = FPDecimal::from_str("0.5").unwrap();
+        assert!(matches!(
+            FPDecimal::from_str("-2").unwrap().checked_pow(half),
+            Err(StdError::GenericErr { .. })
+        ));
+        assert!(matches!(FPDecimal::ZERO.checked_pow(-half), Err(StdError::GenericErr { .. })));
+        assert!(matches!(
+            FPDecimal::ZERO.checked_pow(FPDecimal::NEGATIVE_ONE),
+            Err(StdError::GenericErr { .. })
+        ));
+        assert!(matches!(
+            FPDecimal::TWO.checked_pow(FPDecimal::from(300u128)),
+            Err(StdError::Overflow { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a real number")]
+    fn test_pow_panics_with_the_domain_error() {
+        FPDecimal::from_str("-2").unwrap().pow(FPDecimal::from_str("0.5").unwrap());
+    }
+
+    #[test]
+    fn test_powi() {
+        assert_eq!(FPDecimal::from_str("-1.5").unwrap().powi(3), FPDecimal::from_str("-3.375").unwrap());
+        assert_eq!(FPDecimal::from_str("2").unwrap().powi(-2), FPDecimal::from_str("0.25").unwrap());
+        assert_eq!(FPDecimal::from_str("-7").unwrap().powi(0), FPDecimal::ONE);
+        assert!(FPDecimal::from(2u128).checked_powi(300).is_err());
+        assert_eq!(
+            FPDecimal::TWO.pow(FPDecimal::from_str("-3").unwrap()),
+            FPDecimal::from_str("0.125").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pow10() {
+        assert_eq!(FPDecimal::pow10(0), FPDecimal::ONE);
+        assert_eq!(FPDecimal::pow10(-18), FPDecimal::from_str("0.000000000000000001").unwrap());
+        assert_eq!(FPDecimal::pow10(-19), FPDecimal::zero());
+        assert_eq!(FPDecimal::pow10(59), FPDecimal::from(10u128).pow(FPDecimal::from(59u128)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow10_overflow() {
+        FPDecimal::pow10(60);
+    }
+
+    #[test]
+    fn test_nth_root() {
+        let root = |value: &str, n: u32| FPDecimal::from_str(value).unwrap().nth_root(n).unwrap();
+        assert_eq!(root("27", 3), FPDecimal::THREE);
+        assert_eq!(root("-27", 3), FPDecimal::from_str("-3").unwrap());
+        assert_eq!(root("1000000", 6), FPDecimal::from(10u128));
+        assert_eq!(root("0.001", 3), FPDecimal::from_str("0.1").unwrap());
+        assert_eq!(root("2", 2), FPDecimal::from_str("1.414213562373095048").unwrap());
+        assert_eq!(root("123456789.123", 7), FPDecimal::from_str("14.319594210574626723").unwrap());
+        assert!(FPDecimal::from_str("-4").unwrap().nth_root(2).is_err());
+        assert!(FPDecimal::TWO.nth_root(0).is_err());
+    }
+
+    #[test]
+    fn test_nth_root_of_tiny_values() {
+        // root^(n - 1) truncates to zero for these degrees, so Newton's method cannot refine the estimate
+        let tiny = FPDecimal::from_str("0.000000000000000001").unwrap();
+        for n in [130u32, 258, 259, 260, 262, 264, 266] {
+            let root = tiny.nth_root(n).unwrap();
+            let expected = FPDecimal::_exp(FPDecimal::from(-18i128) * FPDecimal::LN_10 / FPDecimal::from(n as u128));
+            assert!(
+                root.abs_diff(&expected) < FPDecimal::from_str("0.000000000001").unwrap(),
+                "{:?}",
+                (n, root.to_string())
+            );
+        }
     }
 }
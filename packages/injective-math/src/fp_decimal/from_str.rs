@@ -1,5 +1,6 @@
 use bigint::U256;
 use cosmwasm_std::StdError;
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 use crate::fp_decimal::FPDecimal;
@@ -43,11 +44,20 @@ impl FromStr for FPDecimal {
     }
 }
 
+impl TryFrom<&str> for FPDecimal {
+    type Error = StdError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        FPDecimal::from_str(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::FPDecimal;
     use bigint::U256;
+    use std::convert::TryFrom;
     use std::str::FromStr;
 
     #[test]
@@ -62,6 +72,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(FPDecimal::try_from("-1.5").unwrap(), FPDecimal::from_str("-1.5").unwrap());
+        assert!(FPDecimal::try_from("1.2.3").is_err());
+    }
+
     #[test]
     fn test_from_str_one() {
         let val = FPDecimal::from_str("1");
@@ -1,5 +1,6 @@
 /// Logarithmic functions for FPDecimal
 use crate::fp_decimal::{FPDecimal, U256};
+use cosmwasm_std::{StdError, StdResult};
 
 impl FPDecimal {
     /// natural logarithm
@@ -81,13 +82,33 @@ impl FPDecimal {
     pub fn ln(&self) -> FPDecimal {
         FPDecimal::_ln(*self)
     }
+
+    /// Logarithm to an arbitrary positive base other than 1, relative error below 1e-15
+    pub fn log(&self, base: FPDecimal) -> StdResult<FPDecimal> {
+        if base == FPDecimal::ONE {
+            return Err(StdError::generic_err("Logarithm base cannot be 1"));
+        }
+        self.checked_ln()?.checked_div(base.checked_ln()?)
+    }
+
+    /// Base 10 logarithm, exact for powers of 10
+    pub fn log10(&self) -> StdResult<FPDecimal> {
+        self.checked_ln()?.checked_div(FPDecimal::LN_10)
+    }
+
+    /// Base 2 logarithm, relative error below 1e-15
+    pub fn log2(&self) -> StdResult<FPDecimal> {
+        self.checked_ln()?.checked_div(FPDecimal::LN_2)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use crate::test_utils::assert_close_within;
     use crate::FPDecimal;
     use bigint::U256;
+    use std::str::FromStr;
 
     #[test]
     fn test_ln_sanity() {
@@ -126,4 +147,32 @@ mod tests {
         let one_point_five = FPDecimal::_div(three, two);
         assert_eq!(FPDecimal::_ln(one_point_five), FPDecimal::LN_1_5);
     }
+
+    #[test]
+    fn test_log_bases() {
+        const TOLERANCE: &str = "0.000000000000001";
+
+        assert_eq!(FPDecimal::from(1000u128).log10().unwrap(), FPDecimal::THREE);
+        assert_eq!(FPDecimal::from_str("0.001").unwrap().log10().unwrap(), FPDecimal::from_str("-3").unwrap());
+        assert_close_within(FPDecimal::from_str("0.5").unwrap().log10().unwrap(), "-0.301029995663981195", TOLERANCE);
+        assert_close_within(FPDecimal::from(8u128).log2().unwrap(), "3", TOLERANCE);
+        assert_close_within(
+            FPDecimal::from_str("12345.678").unwrap().log2().unwrap(),
+            "13.591718448138551158",
+            TOLERANCE,
+        );
+        assert_close_within(
+            FPDecimal::from_str("0.3").unwrap().log(FPDecimal::THREE).unwrap(),
+            "-1.095903274289384604",
+            TOLERANCE,
+        );
+    }
+
+    #[test]
+    fn test_log_invalid() {
+        assert!(FPDecimal::zero().log2().is_err());
+        assert!(FPDecimal::from_str("-10").unwrap().log10().is_err());
+        assert!(FPDecimal::TWO.log(FPDecimal::ONE).is_err());
+        assert!(FPDecimal::TWO.log(FPDecimal::zero()).is_err());
+    }
 }
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use bigint::U256;
-use cosmwasm_std::{Uint128, Uint256};
+use cosmwasm_std::{Decimal, Decimal256, Uint128, Uint256};
 use schemars::JsonSchema;
 
 #[allow(clippy::upper_case_acronyms)]
@@ -36,6 +36,31 @@ impl From<i128> for FPDecimal {
     }
 }
 
+impl From<u64> for FPDecimal {
+    fn from(x: u64) -> FPDecimal {
+        FPDecimal::from(u128::from(x))
+    }
+}
+
+impl From<u32> for FPDecimal {
+    fn from(x: u32) -> FPDecimal {
+        FPDecimal::from(u128::from(x))
+    }
+}
+
+impl From<i64> for FPDecimal {
+    fn from(x: i64) -> FPDecimal {
+        FPDecimal::from(i128::from(x))
+    }
+}
+
+impl From<i32> for FPDecimal {
+    fn from(x: i32) -> FPDecimal {
+        FPDecimal::from(i128::from(x))
+    }
+}
+
+/// Integer part of the magnitude, panics on overflow. Prefer [`FPDecimal::checked_to_u128`].
 impl From<FPDecimal> for u128 {
     fn from(x: FPDecimal) -> u128 {
         let num: U256 = x.int().num / FPDecimal::ONE.num;
@@ -58,6 +83,7 @@ impl From<U256> for FPDecimal {
     }
 }
 
+/// Same as the `u128` conversion. Prefer [`FPDecimal::checked_to_uint128`].
 impl From<FPDecimal> for Uint128 {
     fn from(x: FPDecimal) -> Uint128 {
         let number: u128 = x.into();
@@ -77,6 +103,19 @@ impl From<Uint256> for FPDecimal {
     }
 }
 
+// both have 18 decimal places, so the conversion is exact
+impl From<Decimal> for FPDecimal {
+    fn from(x: Decimal) -> FPDecimal {
+        FPDecimal::from(U256::from_little_endian(&x.atomics().u128().to_le_bytes()))
+    }
+}
+
+impl From<Decimal256> for FPDecimal {
+    fn from(x: Decimal256) -> FPDecimal {
+        FPDecimal::from(U256::from_little_endian(&x.atomics().to_le_bytes()))
+    }
+}
+
 // #[cfg(not(target_arch = "wasm32"))]
 // impl convert::From<FPDecimal> for f32 {
 //     fn from(x: FPDecimal) -> f32 {
@@ -143,7 +182,7 @@ impl FPDecimal {
     };
 
     pub const E_10: FPDecimal = FPDecimal {
-        num: U256([1053360796277873851u64, 1194u64, 0, 0]),
+        num: U256([1053370797511887453u64, 1194u64, 0, 0]),
         sign: 1,
     }; // e^10
 
@@ -157,6 +196,11 @@ impl FPDecimal {
         sign: 1,
     }; // ln(10)
 
+    pub const LN_2: FPDecimal = FPDecimal {
+        num: U256([693147180559945309, 0, 0, 0]),
+        sign: 1,
+    }; // ln(2)
+
     pub const LN_1_5: FPDecimal = FPDecimal {
         num: U256([405465108108164382, 0, 0, 0]),
         sign: 1,
@@ -231,7 +275,10 @@ impl FPDecimal {
     }
 }
 
+pub use rounding::RoundingMode;
+
 mod arithmetic;
+mod checked;
 mod comparison;
 mod display;
 mod exp;
@@ -239,5 +286,8 @@ mod factorial;
 mod from_str;
 mod hyper;
 mod log;
+mod rounding;
+mod sdk;
 mod serde;
+mod traits;
 mod trigonometry; // cosmwasm serialization
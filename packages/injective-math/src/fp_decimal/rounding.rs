@@ -0,0 +1,171 @@
+/// Explicit rounding for FPDecimal
+use bigint::U512;
+use cosmwasm_std::{DivideByZeroError, OverflowError, OverflowOperation, StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::fp_decimal::checked::{division_overflow, u512_to_u256};
+use crate::fp_decimal::{FPDecimal, U256};
+
+/// How to resolve digits that do not fit the requested precision.
+/// `Floor` and `Ceil` round towards negative and positive infinity, the `Half*` modes round to the nearest value
+/// and only differ on ties: `HalfUp` moves ties away from zero, `HalfEven` to the even neighbour.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    Floor,
+    Ceil,
+    HalfUp,
+    HalfEven,
+    TowardZero,
+    AwayFromZero,
+}
+
+impl FPDecimal {
+    /// Rounds to `dp` decimal places; anything at or above the 18 stored decimals is returned unchanged
+    pub fn round(&self, dp: u32, mode: RoundingMode) -> FPDecimal {
+        self.checked_round(dp, mode).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// `self * other` rounded to 18 decimals with `mode`, instead of discarding the low digits of the product
+    pub fn mul_round(&self, other: FPDecimal, mode: RoundingMode) -> FPDecimal {
+        self.checked_mul_round(other, mode).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// `self / other` rounded to 18 decimals with `mode`, instead of always truncating
+    pub fn div_round(&self, other: FPDecimal, mode: RoundingMode) -> FPDecimal {
+        self.checked_div_round(other, mode).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// [`FPDecimal::round`] that fails when rounding away from zero overflows
+    pub fn checked_round(&self, dp: u32, mode: RoundingMode) -> StdResult<FPDecimal> {
+        if dp as usize >= FPDecimal::DIGITS {
+            return Ok(*self);
+        }
+        let unit = U256::exp10(FPDecimal::DIGITS - dp as usize);
+        let steps = round_quotient(U512::from(self.num), U512::from(unit), self.is_negative(), mode);
+        FPDecimal::from_rounded(steps * U512::from(unit), self.sign)
+            .ok_or_else(|| StdError::generic_err(format!("Rounding {self} to {dp} decimal places overflows")))
+    }
+
+    /// [`FPDecimal::mul_round`] that fails on overflow
+    pub fn checked_mul_round(&self, other: FPDecimal, mode: RoundingMode) -> StdResult<FPDecimal> {
+        let negative = self.sign != other.sign;
+        let num = round_quotient(self.num.full_mul(other.num), U512::from(FPDecimal::ONE.num), negative, mode);
+        FPDecimal::from_rounded(num, 1 ^ self.sign ^ other.sign).ok_or_else(|| OverflowError::new(OverflowOperation::Mul, self, other).into())
+    }
+
+    /// [`FPDecimal::div_round`] that fails on a zero divisor and on overflow
+    pub fn checked_div_round(&self, other: FPDecimal, mode: RoundingMode) -> StdResult<FPDecimal> {
+        if other.is_zero() {
+            return Err(DivideByZeroError::new(self).into());
+        }
+        let negative = self.sign != other.sign;
+        let num = round_quotient(FPDecimal::ONE.num.full_mul(self.num), U512::from(other.num), negative, mode);
+        FPDecimal::from_rounded(num, 1 ^ self.sign ^ other.sign).ok_or_else(|| division_overflow(*self, other))
+    }
+
+    fn from_rounded(num: U512, sign: i8) -> Option<FPDecimal> {
+        let num = u512_to_u256(num)?;
+        Some(FPDecimal {
+            num,
+            sign: if num.is_zero() { 1 } else { sign },
+        })
+    }
+}
+
+/// Rounds the magnitude `num / den` of a value with the given sign
+fn round_quotient(num: U512, den: U512, negative: bool, mode: RoundingMode) -> U512 {
+    let quotient = num / den;
+    let remainder = num % den;
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    let twice_remainder = remainder + remainder;
+    let round_away = match mode {
+        RoundingMode::TowardZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceil => !negative,
+        RoundingMode::HalfUp => twice_remainder >= den,
+        RoundingMode::HalfEven => twice_remainder > den || (twice_remainder == den && quotient.low_u64() & 1 == 1),
+    };
+
+    if round_away {
+        quotient + U512::one()
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::StdError;
+
+    use crate::test_utils::dec;
+    use crate::{FPDecimal, RoundingMode};
+
+    #[test]
+    fn round_to_decimal_places() {
+        let cases = [
+            ("2.345", RoundingMode::Floor, "2.34", "-2.35"),
+            ("2.345", RoundingMode::Ceil, "2.35", "-2.34"),
+            ("2.345", RoundingMode::HalfUp, "2.35", "-2.35"),
+            ("2.345", RoundingMode::HalfEven, "2.34", "-2.34"),
+            ("2.355", RoundingMode::HalfEven, "2.36", "-2.36"),
+            ("2.346", RoundingMode::HalfEven, "2.35", "-2.35"),
+            ("2.345", RoundingMode::TowardZero, "2.34", "-2.34"),
+            ("2.341", RoundingMode::AwayFromZero, "2.35", "-2.35"),
+        ];
+        for (value, mode, positive, negative) in cases {
+            assert_eq!(dec(value).round(2, mode), dec(positive), "{value} {mode:?}");
+            assert_eq!(dec(&format!("-{value}")).round(2, mode), dec(negative), "-{value} {mode:?}");
+        }
+        assert_eq!(dec("2.5").round(0, RoundingMode::HalfEven), dec("2"));
+        assert_eq!(dec("-0.4").round(0, RoundingMode::Ceil), FPDecimal::zero());
+        assert_eq!(dec("1.23").round(18, RoundingMode::Ceil), dec("1.23"));
+    }
+
+    #[test]
+    fn mul_and_div_round() {
+        let tiny = dec("0.000000001");
+        let product = dec("0.0000000015");
+        assert_eq!(tiny.mul_round(product, RoundingMode::Floor), dec("0.000000000000000001"));
+        assert_eq!(tiny.mul_round(product, RoundingMode::Ceil), dec("0.000000000000000002"));
+        assert_eq!(tiny.mul_round(product, RoundingMode::HalfEven), dec("0.000000000000000002"));
+        assert_eq!(dec("-3").mul_round(dec("1.5"), RoundingMode::Floor), dec("-4.5"));
+
+        let third = FPDecimal::ONE.div_round(dec("3"), RoundingMode::Ceil);
+        assert_eq!(third, dec("0.333333333333333334"));
+        assert_eq!(FPDecimal::TWO.div_round(dec("3"), RoundingMode::HalfUp), dec("0.666666666666666667"));
+        assert_eq!(FPDecimal::TWO.div_round(dec("-3"), RoundingMode::Floor), dec("-0.666666666666666667"));
+        assert_eq!(
+            FPDecimal::TWO.div_round(dec("-3"), RoundingMode::TowardZero),
+            dec("-0.666666666666666666")
+        );
+    }
+
+    #[test]
+    fn checked_rounding_reports_errors() {
+        assert!(matches!(
+            FPDecimal::ONE.checked_div_round(FPDecimal::zero(), RoundingMode::HalfUp),
+            Err(StdError::DivideByZero { .. })
+        ));
+        assert!(matches!(
+            FPDecimal::MAX.checked_mul_round(FPDecimal::TWO, RoundingMode::Floor),
+            Err(StdError::Overflow { .. })
+        ));
+        assert_eq!(
+            FPDecimal::MAX.checked_div_round(dec("0.5"), RoundingMode::Floor).unwrap_err(),
+            StdError::generic_err(format!("Dividing {} by 0.5 overflows", FPDecimal::MAX))
+        );
+        assert!(FPDecimal::MAX.checked_round(0, RoundingMode::Ceil).is_err());
+        assert_eq!(FPDecimal::MAX.checked_round(0, RoundingMode::Floor).unwrap(), FPDecimal::MAX.int());
+        assert_eq!(dec("-2.345").checked_round(2, RoundingMode::Ceil).unwrap(), dec("-2.34"));
+        assert_eq!(
+            FPDecimal::TWO.checked_div_round(dec("3"), RoundingMode::HalfUp).unwrap(),
+            dec("0.666666666666666667")
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use bigint::U256;
+use cosmwasm_std::{StdError, StdResult};
+
+use crate::fp_decimal::FPDecimal;
+
+/// Conversions to and from the string encoding of Cosmos SDK numbers in protobuf messages.
+/// An `sdk.Dec` is its value scaled by 10^18 (e.g. `1.5` is `"1500000000000000000"`),
+/// which is exactly the internal representation of [`FPDecimal`]. An `sdk.Int` is a plain integer.
+impl FPDecimal {
+    pub fn from_sdk_dec_str(value: &str) -> StdResult<FPDecimal> {
+        let (sign, num) = parse_signed_integer(value)?;
+        Ok(FPDecimal::from_parts(num, sign))
+    }
+
+    pub fn to_sdk_dec_str(&self) -> String {
+        if self.is_negative() {
+            format!("-{}", self.num)
+        } else {
+            self.num.to_string()
+        }
+    }
+
+    pub fn from_sdk_int_str(value: &str) -> StdResult<FPDecimal> {
+        let (sign, integer) = parse_signed_integer(value)?;
+        let (num, overflow) = integer.overflowing_mul(FPDecimal::ONE.num);
+        if overflow {
+            return Err(StdError::generic_err(format!("sdk.Int {value} overflows FPDecimal")));
+        }
+        Ok(FPDecimal::from_parts(num, sign))
+    }
+
+    /// Fails for values with a fractional part, truncate them with [`FPDecimal::int`] first if that is intended
+    pub fn to_sdk_int_str(&self) -> StdResult<String> {
+        if !self.is_int() {
+            return Err(StdError::generic_err(format!("{self} is not an integer")));
+        }
+        let integer = self.num / FPDecimal::ONE.num;
+        if self.is_negative() {
+            Ok(format!("-{integer}"))
+        } else {
+            Ok(integer.to_string())
+        }
+    }
+
+    fn from_parts(num: U256, sign: i8) -> FPDecimal {
+        // zero is always positive, so "-0" compares equal to "0"
+        let sign = if num.is_zero() { 1 } else { sign };
+        FPDecimal { num, sign }
+    }
+}
+
+fn parse_signed_integer(value: &str) -> StdResult<(i8, U256)> {
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => (0, digits),
+        None => (1, value),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(StdError::generic_err(format!("Invalid sdk number {value}")));
+    }
+    let num = U256::from_dec_str(digits).map_err(|_| StdError::generic_err(format!("sdk number {value} overflows FPDecimal")))?;
+    Ok((sign, num))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::StdError;
+
+    use crate::FPDecimal;
+
+    #[test]
+    fn sdk_dec_round_trip() {
+        let value = FPDecimal::from_str("-1.5").unwrap();
+        assert_eq!(value.to_sdk_dec_str(), "-1500000000000000000");
+        assert_eq!(FPDecimal::from_sdk_dec_str("-1500000000000000000").unwrap(), value);
+        assert_eq!(
+            FPDecimal::from_sdk_dec_str("1").unwrap(),
+            FPDecimal::from_str("0.000000000000000001").unwrap()
+        );
+        assert_eq!(FPDecimal::from_sdk_dec_str("-0").unwrap(), FPDecimal::zero());
+        assert_eq!(FPDecimal::zero().to_sdk_dec_str(), "0");
+    }
+
+    #[test]
+    fn sdk_int_round_trip() {
+        assert_eq!(FPDecimal::from_sdk_int_str("-42").unwrap(), FPDecimal::from(-42i128));
+        assert_eq!(FPDecimal::from(1_000_000u128).to_sdk_int_str().unwrap(), "1000000");
+        assert_eq!(FPDecimal::from(-7i128).to_sdk_int_str().unwrap(), "-7");
+        assert_eq!(
+            FPDecimal::from_str("1.5").unwrap().to_sdk_int_str().unwrap_err(),
+            StdError::generic_err("1.5 is not an integer")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_sdk_numbers() {
+        assert!(FPDecimal::from_sdk_dec_str("").is_err());
+        assert!(FPDecimal::from_sdk_dec_str("1.5").is_err());
+        assert!(FPDecimal::from_sdk_dec_str("+1").is_err());
+        assert!(FPDecimal::from_sdk_int_str(&"9".repeat(70)).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+/// `num` numeric traits for FPDecimal, so it can be used in generic numeric code
+use cosmwasm_std::StdError;
+use num::traits::{Num, One, Signed, Zero};
+use std::str::FromStr;
+
+use crate::fp_decimal::FPDecimal;
+
+impl Zero for FPDecimal {
+    fn zero() -> Self {
+        FPDecimal::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+impl One for FPDecimal {
+    fn one() -> Self {
+        FPDecimal::ONE
+    }
+}
+
+impl Num for FPDecimal {
+    type FromStrRadixErr = StdError;
+
+    /// Only decimal strings are supported
+    fn from_str_radix(input: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(StdError::generic_err(format!("FPDecimal cannot be parsed in radix {radix}")));
+        }
+        FPDecimal::from_str(input)
+    }
+}
+
+impl Signed for FPDecimal {
+    fn abs(&self) -> Self {
+        FPDecimal::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            FPDecimal::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        if FPDecimal::is_zero(self) {
+            FPDecimal::ZERO
+        } else if FPDecimal::is_negative(self) {
+            FPDecimal::NEGATIVE_ONE
+        } else {
+            FPDecimal::ONE
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        !FPDecimal::is_zero(self) && !FPDecimal::is_negative(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        FPDecimal::is_negative(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Decimal, Decimal256};
+    use num::traits::{Num, One, Signed, Zero};
+    use std::str::FromStr;
+
+    use crate::test_utils::dec;
+    use crate::FPDecimal;
+
+    fn sum_of_squares<T: Num + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::zero(), |acc, &value| acc + value * value)
+    }
+
+    // distance from `value` to the nearest multiple of `step`
+    fn distance_to_grid<T: Signed + Copy + PartialOrd>(value: T, step: T) -> T {
+        let below = Signed::abs(&(value % step));
+        let above = Signed::abs(&step) - below;
+        if below < above {
+            below
+        } else {
+            above
+        }
+    }
+
+    #[test]
+    fn test_generic_numeric_code() {
+        assert_eq!(sum_of_squares(&[dec("1.5"), dec("-2")]), dec("6.25"));
+        assert_eq!(distance_to_grid(dec("7.3"), dec("2")), dec("0.7"));
+        assert_eq!(distance_to_grid(dec("-7.3"), dec("2")), dec("0.7"));
+        assert!(Zero::is_zero(&<FPDecimal as Zero>::zero()));
+        assert_eq!(<FPDecimal as One>::one(), FPDecimal::ONE);
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(Signed::signum(&dec("-2.5")), FPDecimal::NEGATIVE_ONE);
+        assert_eq!(Signed::signum(&FPDecimal::zero()), FPDecimal::zero());
+        assert_eq!(Signed::abs_sub(&dec("1"), &dec("3")), FPDecimal::zero());
+        assert_eq!(Signed::abs_sub(&dec("3"), &dec("1")), FPDecimal::TWO);
+        assert!(Signed::is_positive(&dec("0.1")));
+        assert!(!Signed::is_positive(&FPDecimal::zero()));
+        assert!(Signed::is_negative(&dec("-0.1")));
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(FPDecimal::from_str_radix("-12.5", 10).unwrap(), dec("-12.5"));
+        assert!(FPDecimal::from_str_radix("ff", 16).is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(FPDecimal::from(-7i64), dec("-7"));
+        assert_eq!(FPDecimal::from(u64::MAX), dec("18446744073709551615"));
+        assert_eq!(FPDecimal::from(-3i32), dec("-3"));
+        assert_eq!(FPDecimal::from(42u32), dec("42"));
+
+        assert_eq!(
+            FPDecimal::from(Decimal::from_str("1.000000000000000001").unwrap()),
+            dec("1.000000000000000001")
+        );
+        assert_eq!(FPDecimal::from(Decimal::zero()), FPDecimal::zero());
+        assert_eq!(FPDecimal::from(Decimal256::from_str("123.45").unwrap()), dec("123.45"));
+        assert_eq!(FPDecimal::from(Decimal::MAX).to_string(), Decimal::MAX.to_string());
+        assert_eq!(FPDecimal::from(Decimal256::MAX), FPDecimal::MAX);
+    }
+}
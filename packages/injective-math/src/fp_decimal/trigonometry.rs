@@ -1,6 +1,11 @@
+use bigint::U512;
+use cosmwasm_std::{StdError, StdResult};
 use num::pow::Pow;
 
-use crate::fp_decimal::FPDecimal;
+use crate::fp_decimal::{FPDecimal, U256};
+
+// π/2 with 36 decimals, so that reducing large arguments does not amplify the error of the 18 decimal PI
+const HALF_PI_36: U256 = U256([13528471414441747218, 85153039502163864, 0, 0]);
 
 impl FPDecimal {
     pub fn _cos(mut x: FPDecimal) -> FPDecimal {
@@ -80,12 +85,214 @@ impl FPDecimal {
     pub fn imprecise_sin(&self) -> FPDecimal {
         FPDecimal::_sin(*self)
     }
+
+    /// Sine with the argument reduced modulo π/2 at 36 decimals. The reduction adds an error of about |x| * 3e-37,
+    /// so the absolute error stays below 1e-16 for |x| up to 1e20 and results lose meaning from around 1e36.
+    pub fn sin(&self) -> FPDecimal {
+        let (quadrant, sin, cos) = FPDecimal::_reduced_sin_cos(*self);
+        let value = match quadrant {
+            0 => sin,
+            1 => cos,
+            2 => FPDecimal::ZERO - sin,
+            _ => FPDecimal::ZERO - cos,
+        };
+        if self.is_negative() {
+            FPDecimal::ZERO - value
+        } else {
+            value
+        }
+    }
+
+    /// Cosine with the argument reduced modulo π/2 at 36 decimals. The reduction adds an error of about |x| * 3e-37,
+    /// so the absolute error stays below 1e-16 for |x| up to 1e20 and results lose meaning from around 1e36.
+    pub fn cos(&self) -> FPDecimal {
+        let (quadrant, sin, cos) = FPDecimal::_reduced_sin_cos(*self);
+        match quadrant {
+            0 => cos,
+            1 => FPDecimal::ZERO - sin,
+            2 => FPDecimal::ZERO - cos,
+            _ => sin,
+        }
+    }
+
+    /// Tangent with a relative error of about 1e-18 / |cos x|, i.e. below 1e-12 while |cos x| > 1e-6.
+    /// Fails where the cosine rounds to zero.
+    pub fn tan(&self) -> StdResult<FPDecimal> {
+        self.sin().checked_div(self.cos())
+    }
+
+    /// Arctangent in (-π/2, π/2), absolute error below 1e-16
+    pub fn atan(&self) -> FPDecimal {
+        let x = self.abs();
+        let value = if x > FPDecimal::ONE {
+            FPDecimal::_half_pi() - FPDecimal::_atan_series(FPDecimal::ONE / x)
+        } else {
+            FPDecimal::_atan_series(x)
+        };
+        if self.is_negative() {
+            FPDecimal::ZERO - value
+        } else {
+            value
+        }
+    }
+
+    /// Arcsine in [-π/2, π/2], absolute error below 1e-16
+    pub fn asin(&self) -> StdResult<FPDecimal> {
+        let x = self.abs();
+        if x > FPDecimal::ONE {
+            return Err(StdError::generic_err(format!("asin is undefined for {self}")));
+        }
+        // asin(x) = π/2 - 2 * asin(sqrt((1 - x) / 2)) keeps the precision near 1, where 1 - x^2 cancels
+        let value = if x > FPDecimal::_half() {
+            FPDecimal::_half_pi() - FPDecimal::TWO * FPDecimal::_asin_small(FPDecimal::_half_versine(x))
+        } else {
+            FPDecimal::_asin_small(x)
+        };
+        if self.is_negative() {
+            Ok(FPDecimal::ZERO - value)
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Arccosine in [0, π], absolute error below 1e-16
+    pub fn acos(&self) -> StdResult<FPDecimal> {
+        let x = self.abs();
+        if x > FPDecimal::ONE {
+            return Err(StdError::generic_err(format!("acos is undefined for {self}")));
+        }
+        if x <= FPDecimal::_half() {
+            return Ok(FPDecimal::_half_pi() - self.asin()?);
+        }
+        let value = FPDecimal::TWO * FPDecimal::_asin_small(FPDecimal::_half_versine(x));
+        if self.is_negative() {
+            Ok(FPDecimal::TWO * FPDecimal::_half_pi() - value)
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Angle of the point (x, y) in (-π, π], absolute error below 1e-16. The origin maps to 0 like `f64::atan2`.
+    pub fn atan2(y: FPDecimal, x: FPDecimal) -> FPDecimal {
+        if x.is_zero() && y.is_zero() {
+            return FPDecimal::ZERO;
+        }
+        // divide the smaller magnitude by the larger one so the quotient cannot overflow
+        let angle = if y.abs() <= x.abs() {
+            (y.abs() / x.abs()).atan()
+        } else {
+            FPDecimal::_half_pi() - (x.abs() / y.abs()).atan()
+        };
+        let angle = if x.is_negative() {
+            FPDecimal::TWO * FPDecimal::_half_pi() - angle
+        } else {
+            angle
+        };
+        if y.is_negative() {
+            FPDecimal::ZERO - angle
+        } else {
+            angle
+        }
+    }
+
+    /// Quadrant of |x| and the sine and cosine of its remainder modulo π/2
+    fn _reduced_sin_cos(x: FPDecimal) -> (u64, FPDecimal, FPDecimal) {
+        let scale = U512::from(FPDecimal::ONE.num);
+        let half_pi = U512::from(HALF_PI_36);
+        let x = x.num.full_mul(FPDecimal::ONE.num);
+        let quadrant = (x / half_pi).low_u64() % 4;
+        let remainder = x % half_pi;
+
+        // past π/4 the series converges faster on the complementary angle
+        if remainder + remainder > half_pi {
+            let complement = FPDecimal::from(U256::from((half_pi - remainder) / scale));
+            let (sin, cos) = FPDecimal::_sin_cos_series(complement);
+            (quadrant, cos, sin)
+        } else {
+            let (sin, cos) = FPDecimal::_sin_cos_series(FPDecimal::from(U256::from(remainder / scale)));
+            (quadrant, sin, cos)
+        }
+    }
+
+    // Taylor series for 0 <= x <= π/4, where every term is smaller than the previous one
+    fn _sin_cos_series(x: FPDecimal) -> (FPDecimal, FPDecimal) {
+        let x2 = x * x;
+        let mut sin = x;
+        let mut cos = FPDecimal::ONE;
+        let mut sin_term = x;
+        let mut cos_term = FPDecimal::ONE;
+        let mut n = 1u128;
+        while !sin_term.is_zero() || !cos_term.is_zero() {
+            cos_term = cos_term * x2 / FPDecimal::from(n * (n + 1));
+            sin_term = sin_term * x2 / FPDecimal::from((n + 1) * (n + 2));
+            if n % 4 == 1 {
+                cos -= cos_term;
+                sin -= sin_term;
+            } else {
+                cos += cos_term;
+                sin += sin_term;
+            }
+            n += 2;
+        }
+        (sin, cos)
+    }
+
+    // atan for 0 <= x <= 1: two argument halvings bring x below tan(π/16) before the Taylor series
+    fn _atan_series(x: FPDecimal) -> FPDecimal {
+        let mut x = x;
+        for _ in 0..2 {
+            x = x / (FPDecimal::ONE + FPDecimal::sqrt(FPDecimal::ONE + x * x).unwrap());
+        }
+        let x2 = x * x;
+        let mut sum = x;
+        let mut power = x;
+        let mut n = 3u128;
+        loop {
+            power *= x2;
+            let term = power / FPDecimal::from(n);
+            if term.is_zero() {
+                break;
+            }
+            if n % 4 == 3 {
+                sum -= term;
+            } else {
+                sum += term;
+            }
+            n += 2;
+        }
+        FPDecimal::FOUR * sum
+    }
+
+    // asin for 0 <= x <= 1/2, where 1 - x^2 does not cancel
+    fn _asin_small(x: FPDecimal) -> FPDecimal {
+        FPDecimal::_atan_series(x / FPDecimal::sqrt(FPDecimal::ONE - x * x).unwrap())
+    }
+
+    // sqrt((1 - x) / 2), the sine of half the angle whose cosine is x
+    fn _half_versine(x: FPDecimal) -> FPDecimal {
+        FPDecimal::sqrt((FPDecimal::ONE - x) / FPDecimal::TWO).unwrap()
+    }
+
+    fn _half() -> FPDecimal {
+        FPDecimal::ONE / FPDecimal::TWO
+    }
+
+    fn _half_pi() -> FPDecimal {
+        FPDecimal {
+            num: HALF_PI_36 / FPDecimal::ONE.num,
+            sign: 1,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_utils::assert_close_within;
     use crate::FPDecimal;
     use std::str::FromStr;
+
+    const TOLERANCE: &str = "0.0000000000000001";
+
     fn almost_eq(x: FPDecimal, target: FPDecimal) {
         assert!(((x - target) / x).abs() <= FPDecimal::from_str("0.01").unwrap());
     }
@@ -125,4 +332,58 @@ mod tests {
             FPDecimal::from_str("-0.8414709848").unwrap(),
         );
     }
+
+    #[test]
+    fn test_precise_sin_cos() {
+        let cases = [
+            ("1", "0.841470984807896506", "0.540302305868139717"),
+            ("-4.5", "0.977530117665097055", "-0.210795799430779705"),
+            ("1000000.123", "-0.232419109651987319", "0.972615729601664161"),
+            ("0.000001", "0.000000999999999999", "0.999999999999500000"),
+        ];
+        for (x, sin, cos) in cases {
+            let x = FPDecimal::from_str(x).unwrap();
+            assert_close_within(x.sin(), sin, TOLERANCE);
+            assert_close_within(x.cos(), cos, TOLERANCE);
+        }
+        assert_eq!(FPDecimal::ZERO.sin(), FPDecimal::ZERO);
+        assert_eq!(FPDecimal::ZERO.cos(), FPDecimal::ONE);
+    }
+
+    #[test]
+    fn test_tan() {
+        assert_close_within(FPDecimal::ONE.tan().unwrap(), "1.557407724654902230", TOLERANCE);
+        assert_close_within(FPDecimal::from_str("-2").unwrap().tan().unwrap(), "2.185039863261518991", TOLERANCE);
+    }
+
+    #[test]
+    fn test_inverse_trig() {
+        assert_close_within(FPDecimal::from_str("0.5").unwrap().atan(), "0.463647609000806116", TOLERANCE);
+        assert_close_within(FPDecimal::from_str("-3").unwrap().atan(), "-1.249045772398254425", TOLERANCE);
+        assert_close_within(FPDecimal::from(100u128).atan(), "1.560796660108231381", TOLERANCE);
+
+        let cases = [
+            ("0.3", "0.304692654015397507", "1.266103672779499111"),
+            ("-0.9", "-1.119769514998634186", "2.690565841793530805"),
+            ("0.999999", "1.569382113114672367", "0.001414213680224251"),
+            ("1", "1.570796326794896619", "0"),
+        ];
+        for (x, asin, acos) in cases {
+            let x = FPDecimal::from_str(x).unwrap();
+            assert_close_within(x.asin().unwrap(), asin, TOLERANCE);
+            assert_close_within(x.acos().unwrap(), acos, TOLERANCE);
+        }
+        assert!(FPDecimal::from_str("1.000000000000000001").unwrap().asin().is_err());
+        assert!(FPDecimal::from_str("-2").unwrap().acos().is_err());
+    }
+
+    #[test]
+    fn test_atan2() {
+        let atan2 = |y: &str, x: &str| FPDecimal::atan2(FPDecimal::from_str(y).unwrap(), FPDecimal::from_str(x).unwrap());
+        assert_close_within(atan2("1", "-1"), "2.356194490192344928", TOLERANCE);
+        assert_close_within(atan2("-2", "0.5"), "-1.325817663668032465", TOLERANCE);
+        assert_close_within(atan2("1000000", "0.001"), "1.570796325794896619", TOLERANCE);
+        assert_close_within(atan2("0", "-3"), "3.141592653589793238", TOLERANCE);
+        assert_eq!(atan2("0", "0"), FPDecimal::ZERO);
+    }
 }
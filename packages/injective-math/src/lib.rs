@@ -1,9 +1,26 @@
+pub mod amm;
+pub mod distribution;
 pub mod fp_decimal;
+pub mod matrix;
+pub mod options;
+pub mod rates;
+pub mod rebalance;
+pub mod solver;
+pub mod stats;
+#[cfg(test)]
+pub(crate) mod test_utils;
 mod utils;
 pub mod vector;
 
 use cosmwasm_std::{StdResult, Uint128};
+pub use distribution::*;
 pub use fp_decimal::*;
+pub use matrix::*;
+pub use options::*;
+pub use rates::*;
+pub use rebalance::*;
+pub use solver::*;
+pub use stats::*;
 use std::str::FromStr;
 pub use utils::*;
 pub use vector::*;
@@ -0,0 +1,407 @@
+use bigint::U256;
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::ops::Index;
+
+use crate::fp_decimal::FPDecimal;
+use crate::vector::FPVector;
+
+/// Pivots at most this fraction of the largest entry of the matrix count as zero
+const PIVOT_TOLERANCE: FPDecimal = FPDecimal {
+    num: U256([1_000_000_000, 0, 0, 0]),
+    sign: 1,
+};
+
+/// A dense matrix of FPDecimal stored row by row. Operations on operands of incompatible shapes fail.
+/// Deserialization goes through [`FPMatrix::new`], so the values always fill the shape.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(try_from = "RawMatrix")]
+pub struct FPMatrix {
+    rows: usize,
+    cols: usize,
+    values: Vec<FPDecimal>,
+}
+
+#[derive(Deserialize)]
+struct RawMatrix {
+    rows: usize,
+    cols: usize,
+    values: Vec<FPDecimal>,
+}
+
+impl TryFrom<RawMatrix> for FPMatrix {
+    type Error = StdError;
+
+    fn try_from(raw: RawMatrix) -> StdResult<FPMatrix> {
+        FPMatrix::new(raw.rows, raw.cols, raw.values)
+    }
+}
+
+impl FPMatrix {
+    /// A `rows` x `cols` matrix from its values in row-major order
+    pub fn new(rows: usize, cols: usize, values: Vec<FPDecimal>) -> StdResult<FPMatrix> {
+        let size = rows
+            .checked_mul(cols)
+            .ok_or_else(|| StdError::generic_err(format!("A {rows}x{cols} matrix is too large")))?;
+        if values.len() != size {
+            return Err(StdError::generic_err(format!(
+                "A {rows}x{cols} matrix needs {size} values, got {}",
+                values.len()
+            )));
+        }
+        Ok(FPMatrix { rows, cols, values })
+    }
+
+    pub fn from_rows(rows: &[Vec<FPDecimal>]) -> StdResult<FPMatrix> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return Err(StdError::generic_err("All rows of a matrix must have the same length"));
+        }
+        FPMatrix::new(rows.len(), cols, rows.concat())
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> FPMatrix {
+        FPMatrix {
+            rows,
+            cols,
+            values: vec![FPDecimal::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(size: usize) -> FPMatrix {
+        let mut identity = FPMatrix::zeros(size, size);
+        for i in 0..size {
+            identity.values[i * size + i] = FPDecimal::ONE;
+        }
+        identity
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, row: usize) -> FPVector {
+        FPVector::from(&self.values[row * self.cols..(row + 1) * self.cols])
+    }
+
+    pub fn column(&self, col: usize) -> FPVector {
+        FPVector::new((0..self.rows).map(|row| self[(row, col)]).collect())
+    }
+
+    pub fn transpose(&self) -> FPMatrix {
+        let values = (0..self.cols)
+            .flat_map(|col| (0..self.rows).map(move |row| (row, col)))
+            .map(|index| self[index])
+            .collect();
+        FPMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            values,
+        }
+    }
+
+    pub fn add(&self, other: &FPMatrix) -> StdResult<FPMatrix> {
+        self.ensure_same_shape(other)?;
+        let values = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .map(|(&a, &b)| a.checked_add(b))
+            .collect::<Result<_, _>>()?;
+        FPMatrix::new(self.rows, self.cols, values)
+    }
+
+    pub fn sub(&self, other: &FPMatrix) -> StdResult<FPMatrix> {
+        self.ensure_same_shape(other)?;
+        let values = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .map(|(&a, &b)| a.checked_sub(b))
+            .collect::<Result<_, _>>()?;
+        FPMatrix::new(self.rows, self.cols, values)
+    }
+
+    pub fn mul_const(&self, other: FPDecimal) -> FPMatrix {
+        FPMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            values: self.values.iter().map(|&value| value * other).collect(),
+        }
+    }
+
+    /// Matrix product
+    pub fn mul(&self, other: &FPMatrix) -> StdResult<FPMatrix> {
+        if self.cols != other.rows {
+            return Err(StdError::generic_err(format!(
+                "Cannot multiply a {}x{} by a {}x{} matrix",
+                self.rows, self.cols, other.rows, other.cols
+            )));
+        }
+        let mut product = FPMatrix::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                product.values[row * other.cols + col] = sum_of_products((0..self.cols).map(|k| (self[(row, k)], other[(k, col)])))?;
+            }
+        }
+        Ok(product)
+    }
+
+    pub fn mul_vector(&self, vector: &FPVector) -> StdResult<FPVector> {
+        if self.cols != vector.len() {
+            return Err(StdError::generic_err(format!(
+                "Cannot multiply a {}x{} matrix by a vector of length {}",
+                self.rows,
+                self.cols,
+                vector.len()
+            )));
+        }
+        (0..self.rows)
+            .map(|row| sum_of_products((0..self.cols).map(|col| (self[(row, col)], vector[col]))))
+            .collect::<StdResult<Vec<_>>>()
+            .map(FPVector::new)
+    }
+
+    /// LU decomposition with partial pivoting, fails for non-square and (nearly) singular matrices
+    pub fn lu(&self) -> StdResult<LuDecomposition> {
+        self.decompose()?.ok_or_else(|| StdError::generic_err("Matrix is singular"))
+    }
+
+    /// The decomposition, or None once a pivot falls below `PIVOT_TOLERANCE` relative to the largest entry
+    fn decompose(&self) -> StdResult<Option<LuDecomposition>> {
+        if !self.is_square() {
+            return Err(StdError::generic_err(format!(
+                "LU decomposition needs a square matrix, got {}x{}",
+                self.rows, self.cols
+            )));
+        }
+        let size = self.rows;
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps = 0usize;
+        let largest = self.values.iter().map(|value| value.abs()).max().unwrap_or_default();
+        let tolerance = largest.checked_mul(PIVOT_TOLERANCE)?;
+
+        for col in 0..size {
+            let pivot = (col..size).max_by_key(|&row| lu[(row, col)].abs()).unwrap();
+            if lu[(pivot, col)].abs() <= tolerance {
+                return Ok(None);
+            }
+            if pivot != col {
+                for k in 0..size {
+                    lu.values.swap(pivot * size + k, col * size + k);
+                }
+                permutation.swap(pivot, col);
+                swaps += 1;
+            }
+            for row in col + 1..size {
+                let factor = lu[(row, col)].checked_div(lu[(col, col)])?;
+                lu.values[row * size + col] = factor;
+                for k in col + 1..size {
+                    let value = lu[(row, k)].checked_sub(factor.checked_mul(lu[(col, k)])?)?;
+                    lu.values[row * size + k] = value;
+                }
+            }
+        }
+        Ok(Some(LuDecomposition {
+            lu,
+            permutation,
+            odd_permutation: swaps % 2 == 1,
+        }))
+    }
+
+    /// The x for which self * x = b
+    pub fn solve(&self, b: &FPVector) -> StdResult<FPVector> {
+        self.lu()?.solve(b)
+    }
+
+    pub fn inverse(&self) -> StdResult<FPMatrix> {
+        self.lu()?.inverse()
+    }
+
+    pub fn determinant(&self) -> StdResult<FPDecimal> {
+        match self.decompose()? {
+            Some(lu) => lu.determinant(),
+            None => Ok(FPDecimal::zero()),
+        }
+    }
+
+    fn ensure_same_shape(&self, other: &FPMatrix) -> StdResult<()> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(StdError::generic_err(format!(
+                "Matrix shapes differ: {}x{} and {}x{}",
+                self.rows, self.cols, other.rows, other.cols
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Index<(usize, usize)> for FPMatrix {
+    type Output = FPDecimal;
+
+    fn index(&self, (row, col): (usize, usize)) -> &FPDecimal {
+        assert!(row < self.rows && col < self.cols, "Matrix index out of bounds");
+        &self.values[row * self.cols + col]
+    }
+}
+
+fn sum_of_products(pairs: impl Iterator<Item = (FPDecimal, FPDecimal)>) -> StdResult<FPDecimal> {
+    let mut sum = FPDecimal::zero();
+    for (a, b) in pairs {
+        sum = sum.checked_add(a.checked_mul(b)?)?;
+    }
+    Ok(sum)
+}
+
+/// P * A = L * U, with the unit lower triangular L and upper triangular U packed into one matrix
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LuDecomposition {
+    lu: FPMatrix,
+    permutation: Vec<usize>,
+    odd_permutation: bool,
+}
+
+impl LuDecomposition {
+    pub fn determinant(&self) -> StdResult<FPDecimal> {
+        let mut diagonal = FPDecimal::ONE;
+        for i in 0..self.lu.rows {
+            diagonal = diagonal.checked_mul(self.lu[(i, i)])?;
+        }
+        if self.odd_permutation {
+            Ok(-diagonal)
+        } else {
+            Ok(diagonal)
+        }
+    }
+
+    /// The x for which A * x = b, by forward and back substitution
+    pub fn solve(&self, b: &FPVector) -> StdResult<FPVector> {
+        let size = self.lu.rows;
+        if b.len() != size {
+            return Err(StdError::generic_err(format!(
+                "Cannot solve a {size}x{size} system for a vector of length {}",
+                b.len()
+            )));
+        }
+
+        let mut x: Vec<FPDecimal> = self.permutation.iter().map(|&row| b[row]).collect();
+        for row in 0..size {
+            x[row] = x[row].checked_sub(sum_of_products((0..row).map(|k| (self.lu[(row, k)], x[k])))?)?;
+        }
+        for row in (0..size).rev() {
+            let known = sum_of_products((row + 1..size).map(|k| (self.lu[(row, k)], x[k])))?;
+            x[row] = x[row].checked_sub(known)?.checked_div(self.lu[(row, row)])?;
+        }
+        Ok(FPVector::new(x))
+    }
+
+    pub fn inverse(&self) -> StdResult<FPMatrix> {
+        let size = self.lu.rows;
+        let identity = FPMatrix::identity(size);
+        let columns = (0..size).map(|col| self.solve(&identity.column(col))).collect::<StdResult<Vec<_>>>()?;
+        let rows: Vec<Vec<FPDecimal>> = columns.into_iter().map(FPVector::into_vec).collect();
+        Ok(FPMatrix::from_rows(&rows)?.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_slice, to_vec};
+    use std::str::FromStr;
+
+    use crate::matrix::*;
+    use crate::FPDecimal;
+
+    fn matrix(rows: &[&[i128]]) -> FPMatrix {
+        let rows: Vec<Vec<FPDecimal>> = rows.iter().map(|row| row.iter().map(|&value| FPDecimal::from(value)).collect()).collect();
+        FPMatrix::from_rows(&rows).unwrap()
+    }
+
+    fn vector(values: &[i128]) -> FPVector {
+        FPVector::new(values.iter().map(|&value| FPDecimal::from(value)).collect())
+    }
+
+    fn assert_matrix_close(actual: &FPMatrix, expected: &FPMatrix) {
+        let difference = actual.sub(expected).unwrap();
+        for row in 0..difference.rows() {
+            for col in 0..difference.cols() {
+                assert!(
+                    difference[(row, col)].abs() < FPDecimal::from_str("0.000000000001").unwrap(),
+                    "{:?}",
+                    (row, col, actual[(row, col)].to_string())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn shapes_and_products() {
+        let a = matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(a.transpose(), matrix(&[&[1, 4], &[2, 5], &[3, 6]]));
+        assert_eq!(a.mul(&a.transpose()).unwrap(), matrix(&[&[14, 32], &[32, 77]]));
+        assert_eq!(a.mul_vector(&vector(&[1, 0, -1])).unwrap(), vector(&[-2, -2]));
+        assert_eq!(a.mul(&FPMatrix::identity(3)).unwrap(), a);
+
+        assert!(a.mul(&a).is_err());
+        assert!(a.add(&a.transpose()).is_err());
+        assert!(a.mul_vector(&vector(&[1, 2])).is_err());
+        assert!(FPMatrix::new(2, 2, vec![FPDecimal::ONE; 3]).is_err());
+        assert!(FPMatrix::from_rows(&[vec![FPDecimal::ONE], vec![]]).is_err());
+        assert!(a.lu().is_err());
+    }
+
+    #[test]
+    fn deserialization_checks_the_shape() {
+        let a = matrix(&[&[1, 2], &[3, 4]]);
+        assert_eq!(from_slice::<FPMatrix>(&to_vec(&a).unwrap()).unwrap(), a);
+        assert!(from_slice::<FPMatrix>(br#"{"rows":2,"cols":2,"values":["1"]}"#).is_err());
+        assert!(from_slice::<FPMatrix>(br#"{"rows":18446744073709551615,"cols":2,"values":[]}"#).is_err());
+    }
+
+    #[test]
+    fn solve_invert_and_determinant() {
+        // the first pivot is zero, so the decomposition has to swap rows
+        let a = matrix(&[&[0, 2, 1], &[1, 1, 0], &[2, 0, 3]]);
+        assert_eq!(a.determinant().unwrap(), FPDecimal::from(-8i128));
+        assert_eq!(a.solve(&vector(&[7, 3, 11])).unwrap(), vector(&[1, 2, 3]));
+
+        let inverse = a.inverse().unwrap();
+        assert_matrix_close(&a.mul(&inverse).unwrap(), &FPMatrix::identity(3));
+
+        let singular = matrix(&[&[1, 2], &[2, 4]]);
+        assert!(singular.inverse().is_err());
+        assert_eq!(singular.determinant().unwrap(), FPDecimal::zero());
+    }
+
+    #[test]
+    fn rejects_nearly_singular_matrices_and_overflow() {
+        // the second pivot is 1e-15, far below the tolerance relative to the entries
+        let nearly_singular = FPMatrix::from_rows(&[
+            vec![FPDecimal::ONE, FPDecimal::ONE],
+            vec![FPDecimal::ONE, FPDecimal::from_str("1.000000000000001").unwrap()],
+        ])
+        .unwrap();
+        assert!(nearly_singular.solve(&vector(&[1, 1])).is_err());
+        assert_eq!(nearly_singular.determinant().unwrap(), FPDecimal::zero());
+
+        // a small matrix is fine as long as its pivots are large relative to its entries
+        let small = FPMatrix::identity(2).mul_const(FPDecimal::from_str("0.000000000001").unwrap());
+        assert_eq!(small.solve(&vector(&[0, 0])).unwrap(), vector(&[0, 0]));
+
+        let huge = FPMatrix::from_rows(&[vec![FPDecimal::MAX, FPDecimal::ONE], vec![FPDecimal::ONE, FPDecimal::MAX]]).unwrap();
+        assert!(huge.mul(&huge).is_err());
+        assert!(huge.add(&huge).is_err());
+        assert!(huge.mul_vector(&vector(&[2, 0])).is_err());
+    }
+}
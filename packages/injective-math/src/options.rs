@@ -0,0 +1,256 @@
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::distribution::{normal_cdf, normal_pdf};
+use crate::fp_decimal::FPDecimal;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+/// `BlackScholes` prices options on a spot `underlying`, `Black76` options on a forward or futures price
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PricingModel {
+    BlackScholes,
+    Black76,
+}
+
+/// A European option. `time_to_expiry` is in years and `rate` is the continuously compounded risk-free rate.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct EuropeanOption {
+    pub kind: OptionKind,
+    pub underlying: FPDecimal,
+    pub strike: FPDecimal,
+    pub time_to_expiry: FPDecimal,
+    pub rate: FPDecimal,
+}
+
+/// Price and Greeks of an option. `vega` and `rho` are per unit (1.0 = 100%) change of volatility and rate,
+/// `theta` is per year.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct OptionValuation {
+    pub price: FPDecimal,
+    pub delta: FPDecimal,
+    pub gamma: FPDecimal,
+    pub vega: FPDecimal,
+    pub theta: FPDecimal,
+    pub rho: FPDecimal,
+}
+
+fn validate(option: &EuropeanOption, volatility: FPDecimal) -> StdResult<()> {
+    if option.underlying <= FPDecimal::zero() || option.strike <= FPDecimal::zero() {
+        return Err(StdError::generic_err("Underlying price and strike must be positive"));
+    }
+    if option.time_to_expiry <= FPDecimal::zero() {
+        return Err(StdError::generic_err("Time to expiry must be positive"));
+    }
+    if volatility <= FPDecimal::zero() {
+        return Err(StdError::generic_err("Volatility must be positive"));
+    }
+    Ok(())
+}
+
+/// Price and Greeks under `model`, as the generalized Black-Scholes-Merton formula whose cost of carry is
+/// the risk-free rate for spot options and zero for options on forwards
+pub fn value_option(model: PricingModel, option: &EuropeanOption, volatility: FPDecimal) -> StdResult<OptionValuation> {
+    validate(option, volatility)?;
+
+    let EuropeanOption {
+        kind,
+        underlying,
+        strike,
+        time_to_expiry: time,
+        rate,
+    } = *option;
+    let carry = match model {
+        PricingModel::BlackScholes => rate,
+        PricingModel::Black76 => FPDecimal::zero(),
+    };
+    let sqrt_time = FPDecimal::sqrt(time).unwrap();
+    let vol_sqrt_time = volatility * sqrt_time;
+    let d1 = ((underlying / strike).ln() + (carry + volatility * volatility / FPDecimal::TWO) * time) / vol_sqrt_time;
+    let d2 = d1 - vol_sqrt_time;
+
+    // a large negative rate over a long time overflows the discount factors
+    let carry_discount = (carry - rate).checked_mul(time)?.checked_exp()?;
+    let discount = (-rate).checked_mul(time)?.checked_exp()?;
+    let density = normal_pdf(d1);
+
+    let gamma = carry_discount * density / (underlying * vol_sqrt_time);
+    let vega = underlying * carry_discount * density * sqrt_time;
+    let time_decay = -(underlying * carry_discount * density * volatility / (FPDecimal::TWO * sqrt_time));
+
+    let (price, delta, theta, rate_sensitivity) = match kind {
+        OptionKind::Call => {
+            let (n1, n2) = (normal_cdf(d1), normal_cdf(d2));
+            (
+                underlying * carry_discount * n1 - strike * discount * n2,
+                carry_discount * n1,
+                time_decay - (carry - rate) * underlying * carry_discount * n1 - rate * strike * discount * n2,
+                strike * time * discount * n2,
+            )
+        }
+        OptionKind::Put => {
+            let (n1, n2) = (normal_cdf(-d1), normal_cdf(-d2));
+            (
+                strike * discount * n2 - underlying * carry_discount * n1,
+                -(carry_discount * n1),
+                time_decay + (carry - rate) * underlying * carry_discount * n1 + rate * strike * discount * n2,
+                -(strike * time * discount * n2),
+            )
+        }
+    };
+
+    let rho = match model {
+        PricingModel::BlackScholes => rate_sensitivity,
+        // on a forward the rate only discounts the payoff
+        PricingModel::Black76 => -(time * price),
+    };
+
+    Ok(OptionValuation {
+        price,
+        delta,
+        gamma,
+        vega,
+        theta,
+        rho,
+    })
+}
+
+/// Black-Scholes price and Greeks of an option on a spot price
+pub fn black_scholes(option: &EuropeanOption, volatility: FPDecimal) -> StdResult<OptionValuation> {
+    value_option(PricingModel::BlackScholes, option, volatility)
+}
+
+/// Black-76 price and Greeks of an option on a forward or futures price
+pub fn black_76(option: &EuropeanOption, volatility: FPDecimal) -> StdResult<OptionValuation> {
+    value_option(PricingModel::Black76, option, volatility)
+}
+
+/// Volatility at which `model` prices `option` at `price`, searched between 0.1% and 1000%.
+/// Newton steps on vega are used while they stay inside the bracket, bisection otherwise.
+pub fn implied_volatility(model: PricingModel, option: &EuropeanOption, price: FPDecimal) -> StdResult<FPDecimal> {
+    const MAX_ITERATIONS: usize = 100;
+
+    let price_at = |volatility: FPDecimal| value_option(model, option, volatility);
+    let mut low = FPDecimal::from_str("0.001").unwrap();
+    let mut high = FPDecimal::from(10u128);
+    if price < price_at(low)?.price || price > price_at(high)?.price {
+        return Err(StdError::generic_err(format!(
+            "No volatility between {low} and {high} yields price {price}"
+        )));
+    }
+
+    let tolerance = FPDecimal::from_str("0.000000000001").unwrap();
+    let mut volatility = FPDecimal::from_str("0.5").unwrap();
+    for _ in 0..MAX_ITERATIONS {
+        let valuation = price_at(volatility)?;
+        let error = valuation.price - price;
+        if error.abs() <= tolerance * price.abs().max(FPDecimal::ONE) {
+            return Ok(volatility);
+        }
+        // price increases with volatility
+        if error.is_negative() {
+            low = volatility;
+        } else {
+            high = volatility;
+        }
+
+        let newton = if valuation.vega.is_zero() {
+            None
+        } else {
+            Some(volatility - error / valuation.vega)
+        };
+        volatility = match newton {
+            Some(next) if next > low && next < high => next,
+            _ => (low + high) / FPDecimal::TWO,
+        };
+    }
+    Err(StdError::generic_err("Implied volatility did not converge"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::options::*;
+    use crate::test_utils::{assert_close, dec};
+    use crate::FPDecimal;
+
+    fn option(kind: OptionKind, underlying: &str, strike: &str, time: &str, rate: &str) -> EuropeanOption {
+        EuropeanOption {
+            kind,
+            underlying: dec(underlying),
+            strike: dec(strike),
+            time_to_expiry: dec(time),
+            rate: dec(rate),
+        }
+    }
+
+    #[test]
+    fn black_scholes_call_and_put() {
+        let call = black_scholes(&option(OptionKind::Call, "100", "100", "1", "0.05"), dec("0.2")).unwrap();
+        assert_close(call.price, "10.450583572185567");
+        assert_close(call.delta, "0.636830651175619");
+        assert_close(call.gamma, "0.018762017345847");
+        assert_close(call.vega, "37.524034691693788");
+        assert_close(call.theta, "-6.414027546438196");
+        assert_close(call.rho, "53.232481545376340");
+
+        let put = black_scholes(&option(OptionKind::Put, "100", "100", "1", "0.05"), dec("0.2")).unwrap();
+        assert_close(put.price, "5.573526022256968");
+        assert_close(put.delta, "-0.363169348824381");
+        assert_close(put.gamma, call.gamma.to_string().as_str());
+        assert_close(put.theta, "-1.657880423934626");
+        assert_close(put.rho, "-41.890460904695061");
+    }
+
+    #[test]
+    fn black_scholes_rho_without_rates() {
+        // K * T * N(d2) with d2 = -0.1
+        let call = black_scholes(&option(OptionKind::Call, "100", "100", "1", "0"), dec("0.2")).unwrap();
+        assert_close(call.rho, "46.017216272297101");
+    }
+
+    #[test]
+    fn black_76_call_and_put() {
+        let call = black_76(&option(OptionKind::Call, "100", "95", "0.5", "0.03"), dec("0.25")).unwrap();
+        assert_close(call.price, "9.509640037794447");
+        assert_close(call.delta, "0.637848032688346");
+        assert_close(call.rho, "-4.754820018897224");
+
+        // put-call parity on a forward: C - P = (F - K) * exp(-rT)
+        let put = black_76(&option(OptionKind::Put, "100", "95", "0.5", "0.03"), dec("0.25")).unwrap();
+        assert_close(call.price - put.price, "4.925559698015313");
+    }
+
+    #[test]
+    fn implied_volatility_round_trip() {
+        for (kind, model) in [
+            (OptionKind::Call, PricingModel::BlackScholes),
+            (OptionKind::Put, PricingModel::BlackScholes),
+            (OptionKind::Call, PricingModel::Black76),
+        ] {
+            let option = option(kind, "2000", "2300", "0.25", "0.04");
+            let price = value_option(model, &option, dec("0.65")).unwrap().price;
+            let volatility = implied_volatility(model, &option, price).unwrap();
+            assert!(volatility.abs_diff(&dec("0.65")) < dec("0.000000001"));
+        }
+
+        let call = option(OptionKind::Call, "100", "100", "1", "0.05");
+        assert!(implied_volatility(PricingModel::BlackScholes, &call, dec("150")).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        assert!(black_scholes(&option(OptionKind::Call, "100", "100", "0", "0.05"), dec("0.2")).is_err());
+        assert!(black_scholes(&option(OptionKind::Call, "100", "0", "1", "0.05"), dec("0.2")).is_err());
+        assert!(black_76(&option(OptionKind::Put, "100", "100", "1", "0.05"), FPDecimal::zero()).is_err());
+        assert!(black_scholes(&option(OptionKind::Call, "100", "100", "100", "-2"), dec("0.2")).is_err());
+        assert!(black_76(&option(OptionKind::Put, "100", "100", "100", "-2"), dec("0.2")).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::fp_decimal::FPDecimal;
+
+/// Length of the 365 day year that annual rates refer to
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
+fn ensure_periods(periods_per_year: u32) -> StdResult<FPDecimal> {
+    if periods_per_year == 0 {
+        return Err(StdError::generic_err("Compounding needs at least one period per year"));
+    }
+    Ok(FPDecimal::from(periods_per_year as u128))
+}
+
+/// Years between two block timestamps in seconds, fails if `end` is before `start` or the span overflows `i64`
+pub fn year_fraction(start: i64, end: i64) -> StdResult<FPDecimal> {
+    Ok(FPDecimal::from(elapsed_seconds(start, end)? as i128) / FPDecimal::from(SECONDS_PER_YEAR as i128))
+}
+
+fn elapsed_seconds(start: i64, end: i64) -> StdResult<i64> {
+    if end < start {
+        return Err(StdError::generic_err(format!("Timestamp {end} is before {start}")));
+    }
+    end.checked_sub(start)
+        .ok_or_else(|| StdError::generic_err(format!("Time between {start} and {end} overflows")))
+}
+
+/// `principal` grown at `rate` compounded continuously for `years`, P * e^(r * t)
+pub fn continuous_compound(principal: FPDecimal, rate: FPDecimal, years: FPDecimal) -> StdResult<FPDecimal> {
+    let growth = rate.checked_mul(years)?.checked_exp()?;
+    Ok(principal.checked_mul(growth)?)
+}
+
+/// `principal` grown at the annual `rate` compounded `periods_per_year` times a year for `years`, P * (1 + r / n)^(n * t)
+pub fn discrete_compound(principal: FPDecimal, rate: FPDecimal, periods_per_year: u32, years: FPDecimal) -> StdResult<FPDecimal> {
+    let periods = ensure_periods(periods_per_year)?;
+    let growth = (FPDecimal::ONE + rate.checked_div(periods)?).powf(periods.checked_mul(years)?)?;
+    Ok(principal.checked_mul(growth)?)
+}
+
+/// Effective annual yield of `apr` compounded `periods_per_year` times a year, (1 + apr / n)^n - 1
+pub fn apr_to_apy(apr: FPDecimal, periods_per_year: u32) -> StdResult<FPDecimal> {
+    let periods = ensure_periods(periods_per_year)?;
+    let growth = (FPDecimal::ONE + apr / periods).checked_powi(periods_per_year as i64)?;
+    Ok(growth - FPDecimal::ONE)
+}
+
+/// The rate that compounded `periods_per_year` times a year yields `apy`, n * ((1 + apy)^(1 / n) - 1)
+pub fn apy_to_apr(apy: FPDecimal, periods_per_year: u32) -> StdResult<FPDecimal> {
+    let periods = ensure_periods(periods_per_year)?;
+    let growth = (FPDecimal::ONE + apy).nth_root(periods_per_year)?;
+    Ok(periods * (growth - FPDecimal::ONE))
+}
+
+/// Effective annual yield of a continuously compounded `apr`, e^apr - 1
+pub fn continuous_apr_to_apy(apr: FPDecimal) -> StdResult<FPDecimal> {
+    Ok(apr.checked_exp()? - FPDecimal::ONE)
+}
+
+/// Continuously compounded rate that yields `apy`, ln(1 + apy)
+pub fn apy_to_continuous_apr(apy: FPDecimal) -> StdResult<FPDecimal> {
+    (FPDecimal::ONE + apy).checked_ln()
+}
+
+/// Simple interest rate per second of an annual rate
+pub fn per_second_rate(apr: FPDecimal) -> FPDecimal {
+    apr / FPDecimal::from(SECONDS_PER_YEAR as i128)
+}
+
+/// Growth of a balance accruing `apr` compounded every second between two block timestamps, (1 + apr / year)^seconds
+pub fn accrual_factor(apr: FPDecimal, start: i64, end: i64) -> StdResult<FPDecimal> {
+    let seconds = elapsed_seconds(start, end)?;
+    Ok((FPDecimal::ONE + per_second_rate(apr)).checked_powi(seconds)?)
+}
+
+/// Borrow or supply index after accruing `apr` per second from `last_update` to `now`
+pub fn accrue_index(index: FPDecimal, apr: FPDecimal, last_update: i64, now: i64) -> StdResult<FPDecimal> {
+    Ok(index.checked_mul(accrual_factor(apr, last_update, now)?)?)
+}
+
+/// Share of a lending pool's funds that is borrowed, borrows / (cash + borrows - reserves)
+pub fn utilization(cash: FPDecimal, borrows: FPDecimal, reserves: FPDecimal) -> StdResult<FPDecimal> {
+    if borrows.is_zero() {
+        return Ok(FPDecimal::zero());
+    }
+    let funds = cash + borrows - reserves;
+    if funds <= FPDecimal::zero() {
+        return Err(StdError::generic_err("Pool funds must be positive"));
+    }
+    Ok(borrows / funds)
+}
+
+/// Aave and Compound style interest rate model whose borrow rate rises along `slope_low` up to
+/// `optimal_utilization` and along the steeper `slope_high` beyond it. All rates are annual.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct KinkedRateModel {
+    pub base_rate: FPDecimal,
+    pub slope_low: FPDecimal,
+    pub slope_high: FPDecimal,
+    pub optimal_utilization: FPDecimal,
+    pub reserve_factor: FPDecimal,
+}
+
+impl KinkedRateModel {
+    pub fn validate(&self) -> StdResult<()> {
+        if self.base_rate.is_negative() || self.slope_low.is_negative() || self.slope_high.is_negative() {
+            return Err(StdError::generic_err("Rates and slopes must not be negative"));
+        }
+        if self.optimal_utilization <= FPDecimal::zero() || self.optimal_utilization >= FPDecimal::ONE {
+            return Err(StdError::generic_err("Optimal utilization must be in (0, 1)"));
+        }
+        if self.reserve_factor.is_negative() || self.reserve_factor > FPDecimal::ONE {
+            return Err(StdError::generic_err("Reserve factor must be in [0, 1]"));
+        }
+        Ok(())
+    }
+
+    /// Annual borrow rate at `utilization` in [0, 1]
+    pub fn borrow_rate(&self, utilization: FPDecimal) -> StdResult<FPDecimal> {
+        self.validate()?;
+        if utilization.is_negative() || utilization > FPDecimal::ONE {
+            return Err(StdError::generic_err(format!("Utilization must be in [0, 1], got {utilization}")));
+        }
+        if utilization <= self.optimal_utilization {
+            return Ok(self.base_rate + self.slope_low * utilization / self.optimal_utilization);
+        }
+        let excess = (utilization - self.optimal_utilization) / (FPDecimal::ONE - self.optimal_utilization);
+        Ok(self.base_rate + self.slope_low + self.slope_high * excess)
+    }
+
+    /// Annual rate earned by suppliers, the borrow interest spread over all funds net of the reserve cut
+    pub fn supply_rate(&self, utilization: FPDecimal) -> StdResult<FPDecimal> {
+        Ok(self.borrow_rate(utilization)? * utilization * (FPDecimal::ONE - self.reserve_factor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rates::*;
+    use crate::test_utils::{assert_close, dec};
+    use crate::FPDecimal;
+
+    #[test]
+    fn compounding() {
+        let rate = dec("0.05");
+        assert_close(continuous_compound(dec("1000"), rate, dec("2")).unwrap(), "1105.170918075647624811");
+        assert_close(discrete_compound(dec("1000"), rate, 12, dec("2")).unwrap(), "1104.941335558327274663");
+        assert_close(apr_to_apy(rate, 365).unwrap(), "0.051267496467462550");
+        assert_close(apy_to_apr(dec("0.051267496467462550"), 365).unwrap(), "0.05");
+        assert_close(continuous_apr_to_apy(rate).unwrap(), "0.051271096376024040");
+        assert_close(apy_to_continuous_apr(dec("0.051271096376024040")).unwrap(), "0.05");
+        assert!(apr_to_apy(rate, 0).is_err());
+        assert!(continuous_compound(FPDecimal::ONE, FPDecimal::TWO, dec("100")).is_err());
+        assert!(discrete_compound(FPDecimal::MAX, rate, 12, dec("2")).is_err());
+        assert!(continuous_apr_to_apy(dec("150")).is_err());
+    }
+
+    #[test]
+    fn accrual_between_timestamps() {
+        assert_eq!(year_fraction(1_000, 1_000 + SECONDS_PER_YEAR / 2).unwrap(), dec("0.5"));
+        assert!(year_fraction(10, 9).is_err());
+        assert!(year_fraction(i64::MIN, i64::MAX).is_err());
+
+        // compounding every second over a year is all but continuous
+        let factor = accrual_factor(dec("0.1"), 0, SECONDS_PER_YEAR).unwrap();
+        assert!(factor.abs_diff(&continuous_compound(FPDecimal::ONE, dec("0.1"), FPDecimal::ONE).unwrap()) < dec("0.0000001"));
+        assert_eq!(accrue_index(dec("1.5"), dec("0.1"), 100, 100).unwrap(), dec("1.5"));
+        assert_eq!(
+            accrue_index(FPDecimal::ONE, dec("0.1"), 0, 1).unwrap(),
+            FPDecimal::ONE + per_second_rate(dec("0.1"))
+        );
+    }
+
+    #[test]
+    fn kinked_rate_model() {
+        let model = KinkedRateModel {
+            base_rate: dec("0.02"),
+            slope_low: dec("0.04"),
+            slope_high: dec("0.75"),
+            optimal_utilization: dec("0.8"),
+            reserve_factor: dec("0.1"),
+        };
+        assert_eq!(model.borrow_rate(FPDecimal::zero()).unwrap(), dec("0.02"));
+        assert_eq!(model.borrow_rate(dec("0.4")).unwrap(), dec("0.04"));
+        assert_eq!(model.borrow_rate(dec("0.8")).unwrap(), dec("0.06"));
+        assert_eq!(model.borrow_rate(dec("0.9")).unwrap(), dec("0.435"));
+        assert_eq!(model.supply_rate(dec("0.4")).unwrap(), dec("0.0144"));
+        assert!(model.borrow_rate(dec("1.1")).is_err());
+
+        assert_eq!(utilization(dec("60"), dec("40"), FPDecimal::zero()).unwrap(), dec("0.4"));
+        assert_eq!(utilization(dec("60"), FPDecimal::zero(), dec("5")).unwrap(), FPDecimal::zero());
+        assert!(utilization(FPDecimal::zero(), dec("1"), dec("2")).is_err());
+
+        let invalid = KinkedRateModel {
+            optimal_utilization: FPDecimal::ONE,
+            ..model
+        };
+        assert!(invalid.borrow_rate(dec("0.5")).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::fp_decimal::FPDecimal;
+
+/// One asset of a portfolio to rebalance. `target_weight` has the meaning it has in [`crate::imbalance`]:
+/// the target inventory is proportional to it, so the target value share is proportional to `target_weight * price`.
+/// Traded quantities are multiples of `tick` (zero for none) and at least `min_trade`;
+/// `fee_rate` is charged on the notional of every trade.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RebalanceAsset {
+    pub inventory: FPDecimal,
+    pub price: FPDecimal,
+    pub target_weight: FPDecimal,
+    pub min_trade: FPDecimal,
+    pub tick: FPDecimal,
+    pub fee_rate: FPDecimal,
+}
+
+/// A trade of the asset at `index`, `quantity` is positive for buys and negative for sells
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Trade {
+    pub index: usize,
+    pub quantity: FPDecimal,
+    pub fee: FPDecimal,
+}
+
+/// Trades of a rebalance, the imbalance left after them and the sale proceeds that were not spent
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RebalancePlan {
+    pub trades: Vec<Trade>,
+    pub imbalance: FPDecimal,
+    pub fees: FPDecimal,
+    pub residual_cash: FPDecimal,
+}
+
+fn validate(assets: &[RebalanceAsset], max_turnover: Option<FPDecimal>) -> StdResult<()> {
+    if assets.is_empty() {
+        return Err(StdError::generic_err("Nothing to rebalance"));
+    }
+    for (index, asset) in assets.iter().enumerate() {
+        if asset.price <= FPDecimal::zero() {
+            return Err(StdError::generic_err(format!("Price of asset {index} must be positive")));
+        }
+        if asset.inventory.is_negative() || asset.target_weight.is_negative() || asset.min_trade.is_negative() || asset.tick.is_negative() {
+            return Err(StdError::generic_err(format!(
+                "Inventory, weight, min trade and tick of asset {index} must not be negative"
+            )));
+        }
+        if asset.fee_rate.is_negative() || asset.fee_rate >= FPDecimal::ONE {
+            return Err(StdError::generic_err(format!("Fee rate of asset {index} must be in [0, 1)")));
+        }
+    }
+    if assets.iter().all(|asset| asset.target_weight.is_zero()) {
+        return Err(StdError::generic_err("At least one target weight must be positive"));
+    }
+    if matches!(max_turnover, Some(cap) if cap.is_negative()) {
+        return Err(StdError::generic_err("Turnover cap must not be negative"));
+    }
+    Ok(())
+}
+
+fn round_to_tick(quantity: FPDecimal, tick: FPDecimal) -> StdResult<FPDecimal> {
+    if tick.is_zero() {
+        Ok(quantity)
+    } else {
+        Ok(quantity.checked_div(tick)?.int().checked_mul(tick)?)
+    }
+}
+
+// the smallest multiple of a positive tick that is at least the minimum trade size
+fn smallest_trade(asset: &RebalanceAsset) -> StdResult<FPDecimal> {
+    let quantity = round_to_tick(asset.min_trade, asset.tick)?;
+    if quantity < asset.min_trade || quantity.is_zero() {
+        Ok(quantity.checked_add(asset.tick)?)
+    } else {
+        Ok(quantity)
+    }
+}
+
+// rounds down to the tick and drops what is below the minimum trade size
+fn tradable(quantity: FPDecimal, asset: &RebalanceAsset) -> StdResult<FPDecimal> {
+    let quantity = round_to_tick(quantity, asset.tick)?;
+    if quantity.is_zero() || quantity < asset.min_trade {
+        Ok(FPDecimal::zero())
+    } else {
+        Ok(quantity)
+    }
+}
+
+// notional of `quantity` plus the fee on it
+fn buy_cost(asset: &RebalanceAsset, quantity: FPDecimal) -> StdResult<FPDecimal> {
+    Ok(quantity
+        .checked_mul(asset.price)?
+        .checked_mul(FPDecimal::ONE.checked_add(asset.fee_rate)?)?)
+}
+
+fn checked_dot(a: &[FPDecimal], b: &[FPDecimal]) -> StdResult<FPDecimal> {
+    a.iter()
+        .zip(b)
+        .try_fold(FPDecimal::zero(), |acc, (&x, &y)| Ok(acc.checked_add(x.checked_mul(y)?)?))
+}
+
+// [`crate::imbalance`] of the inventory after the trades, failing on overflow instead of panicking
+fn checked_imbalance(inventory: &[FPDecimal], prices: &[FPDecimal], weights: &[FPDecimal]) -> StdResult<FPDecimal> {
+    let nav = checked_dot(inventory, prices)?;
+    let weighted_price = checked_dot(weights, prices)?;
+    let mut error = FPDecimal::zero();
+    for ((&held, &price), &weight) in inventory.iter().zip(prices).zip(weights) {
+        let target = weight.checked_mul(price)?.checked_mul(nav)?;
+        let actual = held.checked_mul(price)?.checked_mul(weighted_price)?;
+        error = error.checked_add(target.checked_sub(actual)?.abs())?;
+    }
+    error.checked_div(weighted_price)
+}
+
+/// Trades that bring `assets` closest to their target weights without outside cash.
+///
+/// The ideal trades move every inventory to its target at the current NAV. They are scaled down uniformly when their
+/// notional exceeds `max_turnover`, a fraction of the NAV, which is how imbalance falls fastest per unit traded.
+/// Sells are rounded down to their ticks and minimum sizes, then buys are scaled to what the sales yield after fees
+/// and rounded down in turn. Leftover proceeds finally buy one more tick of the most underweight assets
+/// wherever that tick brings the asset closer to its target. Fails instead of overflowing.
+pub fn rebalance(assets: &[RebalanceAsset], max_turnover: Option<FPDecimal>) -> StdResult<RebalancePlan> {
+    validate(assets, max_turnover)?;
+
+    let inventory: Vec<FPDecimal> = assets.iter().map(|asset| asset.inventory).collect();
+    let prices: Vec<FPDecimal> = assets.iter().map(|asset| asset.price).collect();
+    let weights: Vec<FPDecimal> = assets.iter().map(|asset| asset.target_weight).collect();
+    let nav = checked_dot(&inventory, &prices)?;
+    let weighted_price = checked_dot(&weights, &prices)?;
+
+    // signed distance of every inventory from its target
+    let mut deltas = assets
+        .iter()
+        .map(|asset| {
+            Ok(asset
+                .target_weight
+                .checked_mul(nav)?
+                .checked_div(weighted_price)?
+                .checked_sub(asset.inventory)?)
+        })
+        .collect::<StdResult<Vec<FPDecimal>>>()?;
+
+    let turnover = deltas
+        .iter()
+        .zip(&prices)
+        .try_fold(FPDecimal::zero(), |acc, (delta, &price)| -> StdResult<FPDecimal> {
+            Ok(acc.checked_add(delta.abs().checked_mul(price)?)?)
+        })?;
+    let turnover_limit = max_turnover.map(|cap| cap.checked_mul(nav)).transpose()?;
+    if let Some(limit) = turnover_limit {
+        if turnover > limit {
+            let scale = limit.checked_div(turnover)?;
+            deltas = deltas.iter().map(|&delta| delta.checked_mul(scale)).collect::<Result<_, _>>()?;
+        }
+    }
+
+    let mut quantities = vec![FPDecimal::zero(); assets.len()];
+    let mut cash = FPDecimal::zero();
+    let mut traded = FPDecimal::zero();
+    for (index, asset) in assets.iter().enumerate() {
+        if deltas[index].is_negative() {
+            let sold = tradable(deltas[index].abs().min(asset.inventory), asset)?;
+            let notional = sold.checked_mul(asset.price)?;
+            quantities[index] = -sold;
+            cash = cash.checked_add(notional.checked_mul(FPDecimal::ONE.checked_sub(asset.fee_rate)?)?)?;
+            traded = traded.checked_add(notional)?;
+        }
+    }
+
+    let desired_cost = assets
+        .iter()
+        .zip(&deltas)
+        .filter(|(_, delta)| !delta.is_negative())
+        .try_fold(FPDecimal::zero(), |acc, (asset, &delta)| -> StdResult<FPDecimal> {
+            Ok(acc.checked_add(buy_cost(asset, delta)?)?)
+        })?;
+    let funding = if desired_cost > cash {
+        cash.checked_div(desired_cost)?
+    } else {
+        FPDecimal::ONE
+    };
+    for (index, asset) in assets.iter().enumerate() {
+        if !deltas[index].is_negative() {
+            let bought = tradable(deltas[index].checked_mul(funding)?, asset)?;
+            quantities[index] = bought;
+            cash = cash.checked_sub(buy_cost(asset, bought)?)?;
+            traded = traded.checked_add(bought.checked_mul(asset.price)?)?;
+        }
+    }
+
+    // spend what is left on single ticks, most underweight asset first
+    let mut underweight = Vec::new();
+    for index in 0..assets.len() {
+        if !deltas[index].is_negative() && !assets[index].tick.is_zero() {
+            let remaining = deltas[index].checked_sub(quantities[index])?;
+            underweight.push((index, remaining, remaining.checked_mul(assets[index].price)?));
+        }
+    }
+    underweight.sort_by_key(|&(_, _, value)| std::cmp::Reverse(value));
+    for (index, remaining, _) in underweight {
+        let asset = &assets[index];
+        let step = if quantities[index].is_zero() {
+            smallest_trade(asset)?
+        } else {
+            asset.tick
+        };
+        let cost = buy_cost(asset, step)?;
+        let notional = step.checked_mul(asset.price)?;
+        let within_turnover = match turnover_limit {
+            Some(limit) => traded.checked_add(notional)? <= limit,
+            None => true,
+        };
+        if remaining.checked_mul(FPDecimal::TWO)? >= step && cost <= cash && within_turnover {
+            quantities[index] = quantities[index].checked_add(step)?;
+            cash = cash.checked_sub(cost)?;
+            traded = traded.checked_add(notional)?;
+        }
+    }
+
+    let mut trades = Vec::new();
+    let mut fees = FPDecimal::zero();
+    for (index, asset) in assets.iter().enumerate() {
+        if !quantities[index].is_zero() {
+            let fee = quantities[index].abs().checked_mul(asset.price)?.checked_mul(asset.fee_rate)?;
+            fees = fees.checked_add(fee)?;
+            trades.push(Trade {
+                index,
+                quantity: quantities[index],
+                fee,
+            });
+        }
+    }
+
+    let rebalanced = inventory
+        .iter()
+        .zip(&quantities)
+        .map(|(&held, &quantity)| held.checked_add(quantity))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(RebalancePlan {
+        trades,
+        imbalance: checked_imbalance(&rebalanced, &prices, &weights)?,
+        fees,
+        residual_cash: cash,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rebalance::*;
+    use crate::test_utils::dec;
+    use crate::FPDecimal;
+
+    fn asset(inventory: &str, price: &str, target_weight: &str, tick: &str, fee_rate: &str) -> RebalanceAsset {
+        RebalanceAsset {
+            inventory: dec(inventory),
+            price: dec(price),
+            target_weight: dec(target_weight),
+            min_trade: FPDecimal::zero(),
+            tick: dec(tick),
+            fee_rate: dec(fee_rate),
+        }
+    }
+
+    fn quantities(plan: &RebalancePlan) -> Vec<(usize, FPDecimal)> {
+        plan.trades.iter().map(|trade| (trade.index, trade.quantity)).collect()
+    }
+
+    #[test]
+    fn reaches_the_target_without_frictions() {
+        // NAV 100, targets are 5 of the first asset and 10 of the second
+        let assets = [asset("10", "10", "1", "0", "0"), asset("0", "5", "2", "0", "0")];
+        let plan = rebalance(&assets, None).unwrap();
+        assert_eq!(quantities(&plan), vec![(0, dec("-5")), (1, dec("10"))]);
+        assert_eq!(plan.imbalance, FPDecimal::zero());
+        assert_eq!(plan.residual_cash, FPDecimal::zero());
+    }
+
+    #[test]
+    fn respects_ticks_and_fees() {
+        // selling 5 yields 49.5, a whole unit of the second asset costs 5.05
+        let assets = [asset("10", "10", "1", "1", "0.01"), asset("0", "5", "2", "1", "0.01")];
+        let plan = rebalance(&assets, None).unwrap();
+        assert_eq!(quantities(&plan), vec![(0, dec("-5")), (1, dec("9"))]);
+        assert_eq!(plan.fees, dec("0.95"));
+        assert_eq!(plan.residual_cash, dec("4.05"));
+        assert!(plan.imbalance > FPDecimal::zero());
+
+        let mut assets = assets;
+        assets[1].tick = dec("0.1");
+        let plan = rebalance(&assets, None).unwrap();
+        assert_eq!(quantities(&plan), vec![(0, dec("-5")), (1, dec("9.8"))]);
+        assert_eq!(plan.residual_cash, dec("0.01"));
+    }
+
+    #[test]
+    fn caps_turnover() {
+        let assets = [asset("10", "10", "1", "0", "0"), asset("0", "5", "2", "0", "0")];
+        let plan = rebalance(&assets, Some(dec("0.5"))).unwrap();
+        assert_eq!(quantities(&plan), vec![(0, dec("-2.5")), (1, dec("5"))]);
+
+        // a capped sale below the minimum trade size leaves nothing to fund the buy
+        let mut assets = assets;
+        assets[0].min_trade = dec("3");
+        assert!(rebalance(&assets, Some(dec("0.2"))).unwrap().trades.is_empty());
+
+        assert!(rebalance(&assets, Some(dec("-1"))).is_err());
+        assets[0].inventory = FPDecimal::MAX;
+        assert!(rebalance(&assets, None).is_err());
+        assets[0].price = FPDecimal::zero();
+        assert!(rebalance(&assets, None).is_err());
+    }
+}
@@ -0,0 +1,369 @@
+use cosmwasm_std::{StdError, StdResult};
+use std::str::FromStr;
+
+use crate::fp_decimal::FPDecimal;
+
+// 1 / golden ratio
+const INV_GOLDEN_RATIO: &str = "0.618033988749894848";
+
+/// Stopping rules shared by the solvers: they succeed once the estimate is known to within `tolerance`
+/// and fail after `max_iterations` evaluations of the step
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SolverOptions {
+    pub tolerance: FPDecimal,
+    pub max_iterations: u32,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        SolverOptions {
+            tolerance: FPDecimal::from_str("0.000000000001").unwrap(),
+            max_iterations: 100,
+        }
+    }
+}
+
+/// A converged estimate `x`, the function value `fx` there and the iterations it took
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Solution {
+    pub x: FPDecimal,
+    pub fx: FPDecimal,
+    pub iterations: u32,
+}
+
+fn validate(options: &SolverOptions) -> StdResult<()> {
+    if options.tolerance <= FPDecimal::zero() {
+        return Err(StdError::generic_err("Solver tolerance must be positive"));
+    }
+    if options.max_iterations == 0 {
+        return Err(StdError::generic_err("Solver needs at least one iteration"));
+    }
+    Ok(())
+}
+
+fn validate_bracket(low: FPDecimal, high: FPDecimal) -> StdResult<()> {
+    if low >= high {
+        return Err(StdError::generic_err(format!("Invalid interval [{low}, {high}]")));
+    }
+    Ok(())
+}
+
+fn same_sign(a: FPDecimal, b: FPDecimal) -> bool {
+    !a.is_zero() && !b.is_zero() && a.is_negative() == b.is_negative()
+}
+
+fn midpoint(low: FPDecimal, high: FPDecimal) -> StdResult<FPDecimal> {
+    low.checked_add(high)?.checked_div(FPDecimal::TWO)
+}
+
+fn not_converged(method: &str, options: &SolverOptions, estimate: FPDecimal) -> StdError {
+    StdError::generic_err(format!(
+        "{method} did not converge within {} iterations, last estimate {estimate}",
+        options.max_iterations
+    ))
+}
+
+/// Root of `f` in [`low`, `high`] by bisection, `f(low)` and `f(high)` must not have the same sign
+pub fn bisection<F>(f: F, low: FPDecimal, high: FPDecimal, options: SolverOptions) -> StdResult<Solution>
+where
+    F: Fn(FPDecimal) -> StdResult<FPDecimal>,
+{
+    validate(&options)?;
+    validate_bracket(low, high)?;
+    let (mut low, mut high) = (low, high);
+    let f_low = f(low)?;
+    let f_high = f(high)?;
+    if f_low.is_zero() {
+        return Ok(Solution {
+            x: low,
+            fx: f_low,
+            iterations: 0,
+        });
+    }
+    if f_high.is_zero() {
+        return Ok(Solution {
+            x: high,
+            fx: f_high,
+            iterations: 0,
+        });
+    }
+    if same_sign(f_low, f_high) {
+        return Err(StdError::generic_err(format!("f({low}) and f({high}) do not bracket a root")));
+    }
+
+    let low_negative = f_low.is_negative();
+    let mut mid = low;
+    for iteration in 1..=options.max_iterations {
+        mid = midpoint(low, high)?;
+        let f_mid = f(mid)?;
+        if f_mid.is_zero() || high.checked_sub(low)?.checked_div(FPDecimal::TWO)? <= options.tolerance {
+            return Ok(Solution {
+                x: mid,
+                fx: f_mid,
+                iterations: iteration,
+            });
+        }
+        if f_mid.is_negative() == low_negative {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Err(not_converged("Bisection", &options, mid))
+}
+
+/// Root of `f` by Newton-Raphson iteration from `initial`, `df` being the derivative of `f`.
+/// Converges once a step is no larger than the tolerance.
+pub fn newton_raphson<F, D>(f: F, df: D, initial: FPDecimal, options: SolverOptions) -> StdResult<Solution>
+where
+    F: Fn(FPDecimal) -> StdResult<FPDecimal>,
+    D: Fn(FPDecimal) -> StdResult<FPDecimal>,
+{
+    validate(&options)?;
+    let mut x = initial;
+    for iteration in 1..=options.max_iterations {
+        let fx = f(x)?;
+        if fx.is_zero() {
+            return Ok(Solution {
+                x,
+                fx,
+                iterations: iteration,
+            });
+        }
+        let slope = df(x)?;
+        if slope.is_zero() {
+            return Err(StdError::generic_err(format!("Newton-Raphson hit a zero derivative at {x}")));
+        }
+        let step = fx.checked_div(slope)?;
+        x = x.checked_sub(step)?;
+        if step.abs() <= options.tolerance {
+            return Ok(Solution {
+                x,
+                fx: f(x)?,
+                iterations: iteration,
+            });
+        }
+    }
+    Err(not_converged("Newton-Raphson", &options, x))
+}
+
+/// Root of `f` in [`low`, `high`] by Brent's method, which combines inverse quadratic interpolation and secant steps
+/// with the guaranteed convergence of bisection. `f(low)` and `f(high)` must not have the same sign.
+pub fn brent<F>(f: F, low: FPDecimal, high: FPDecimal, options: SolverOptions) -> StdResult<Solution>
+where
+    F: Fn(FPDecimal) -> StdResult<FPDecimal>,
+{
+    validate(&options)?;
+    validate_bracket(low, high)?;
+    let (mut a, mut b) = (low, high);
+    let (mut fa, mut fb) = (f(a)?, f(b)?);
+    if same_sign(fa, fb) {
+        return Err(StdError::generic_err(format!("f({low}) and f({high}) do not bracket a root")));
+    }
+
+    let (mut c, mut fc) = (b, fb);
+    let mut d = b.checked_sub(a)?;
+    let mut e = d;
+    let tol = options.tolerance.checked_div(FPDecimal::TWO)?;
+    for iteration in 1..=options.max_iterations {
+        // keep the root between b and c
+        if same_sign(fb, fc) {
+            c = a;
+            fc = fa;
+            d = b.checked_sub(a)?;
+            e = d;
+        }
+        // b is the best estimate so far
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let half_interval = c.checked_sub(b)?.checked_div(FPDecimal::TWO)?;
+        if half_interval.abs() <= tol || fb.is_zero() {
+            return Ok(Solution {
+                x: b,
+                fx: fb,
+                iterations: iteration,
+            });
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            let s = fb.checked_div(fa)?;
+            let (mut p, mut q) = if a == c {
+                // secant step
+                (FPDecimal::TWO.checked_mul(half_interval)?.checked_mul(s)?, FPDecimal::ONE.checked_sub(s)?)
+            } else {
+                // inverse quadratic interpolation
+                let q = fa.checked_div(fc)?;
+                let r = fb.checked_div(fc)?;
+                let r_minus_one = r.checked_sub(FPDecimal::ONE)?;
+                let step = FPDecimal::TWO
+                    .checked_mul(half_interval)?
+                    .checked_mul(q)?
+                    .checked_mul(q.checked_sub(r)?)?
+                    .checked_sub(b.checked_sub(a)?.checked_mul(r_minus_one)?)?;
+                (
+                    s.checked_mul(step)?,
+                    q.checked_sub(FPDecimal::ONE)?
+                        .checked_mul(r_minus_one)?
+                        .checked_mul(s.checked_sub(FPDecimal::ONE)?)?,
+                )
+            };
+            if p > FPDecimal::zero() {
+                q = -q;
+            }
+            p = p.abs();
+            let interpolation_limit = FPDecimal::THREE
+                .checked_mul(half_interval)?
+                .checked_mul(q)?
+                .checked_sub(tol.checked_mul(q)?.abs())?;
+            let previous_limit = e.checked_mul(q)?.abs();
+            if FPDecimal::TWO.checked_mul(p)? < interpolation_limit.min(previous_limit) {
+                e = d;
+                d = p.checked_div(q)?;
+            } else {
+                d = half_interval;
+                e = d;
+            }
+        } else {
+            d = half_interval;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b = if d.abs() > tol {
+            b.checked_add(d)?
+        } else if half_interval.is_negative() {
+            b.checked_sub(tol)?
+        } else {
+            b.checked_add(tol)?
+        };
+        fb = f(b)?;
+    }
+    Err(not_converged("Brent's method", &options, b))
+}
+
+/// Minimum of a unimodal `f` on [`low`, `high`] by golden-section search.
+/// As values of `f` only differ by 1e-18, the minimum of a smooth `f` is located to about 1e-9 at best.
+pub fn golden_section_minimize<F>(f: F, low: FPDecimal, high: FPDecimal, options: SolverOptions) -> StdResult<Solution>
+where
+    F: Fn(FPDecimal) -> StdResult<FPDecimal>,
+{
+    validate(&options)?;
+    validate_bracket(low, high)?;
+    let ratio = FPDecimal::from_str(INV_GOLDEN_RATIO).unwrap();
+    let golden_step = |a: FPDecimal, b: FPDecimal| -> StdResult<FPDecimal> { Ok(b.checked_sub(a)?.checked_mul(ratio)?) };
+    let (mut a, mut b) = (low, high);
+    let mut c = b.checked_sub(golden_step(a, b)?)?;
+    let mut d = a.checked_add(golden_step(a, b)?)?;
+    let (mut fc, mut fd) = (f(c)?, f(d)?);
+
+    for iteration in 1..=options.max_iterations {
+        if b.checked_sub(a)? <= options.tolerance {
+            let x = midpoint(a, b)?;
+            return Ok(Solution {
+                x,
+                fx: f(x)?,
+                iterations: iteration,
+            });
+        }
+        // the interior point with the larger value bounds the minimum, the other one is reused
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b.checked_sub(golden_step(a, b)?)?;
+            fc = f(c)?;
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a.checked_add(golden_step(a, b)?)?;
+            fd = f(d)?;
+        }
+    }
+    Err(not_converged("Golden-section search", &options, midpoint(a, b)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solver::*;
+    use crate::test_utils::{assert_close, assert_close_within, dec};
+    use crate::FPDecimal;
+
+    fn square_minus_two(x: FPDecimal) -> StdResult<FPDecimal> {
+        Ok(x * x - FPDecimal::TWO)
+    }
+
+    #[test]
+    fn finds_roots() {
+        let sqrt_two = "1.414213562373095048";
+        let options = SolverOptions::default();
+
+        let solution = bisection(square_minus_two, FPDecimal::ZERO, FPDecimal::TWO, options).unwrap();
+        assert_close(solution.x, sqrt_two);
+        assert!(solution.iterations > 30);
+
+        let solution = newton_raphson(square_minus_two, |x| Ok(FPDecimal::TWO * x), FPDecimal::ONE, options).unwrap();
+        assert_close_within(solution.x, sqrt_two, "0.00000000000000001");
+        assert!(solution.iterations < 10);
+
+        let solution = brent(square_minus_two, FPDecimal::ZERO, FPDecimal::TWO, options).unwrap();
+        assert_close(solution.x, sqrt_two);
+        assert!(solution.iterations < 15);
+
+        // the fixed point of cos
+        let solution = brent(|x| Ok(x.cos() - x), FPDecimal::ZERO, FPDecimal::ONE, options).unwrap();
+        assert_close(solution.x, "0.739085133215160641");
+    }
+
+    #[test]
+    fn minimizes() {
+        let parabola = |x: FPDecimal| Ok((x - dec("1.5")) * (x - dec("1.5")) + FPDecimal::ONE);
+        let solution = golden_section_minimize(parabola, FPDecimal::ZERO, dec("4"), SolverOptions::default()).unwrap();
+        // near the minimum f is flat to within 1e-18 over about 1e-9, which bounds the accuracy of x
+        assert_close_within(solution.x, "1.5", "0.00000001");
+        assert_close_within(solution.fx, "1", "0.000000000000000001");
+    }
+
+    #[test]
+    fn reports_failures() {
+        let options = SolverOptions::default();
+        let few = SolverOptions {
+            max_iterations: 3,
+            ..options
+        };
+        let err = bisection(square_minus_two, FPDecimal::ZERO, FPDecimal::TWO, few).unwrap_err();
+        assert!(err.to_string().contains("did not converge within 3 iterations"));
+        assert!(golden_section_minimize(square_minus_two, FPDecimal::ZERO, FPDecimal::TWO, few).is_err());
+
+        assert!(brent(square_minus_two, FPDecimal::TWO, FPDecimal::from(3u128), options).is_err());
+        assert!(bisection(square_minus_two, FPDecimal::TWO, FPDecimal::ZERO, options).is_err());
+        assert!(newton_raphson(square_minus_two, |_| Ok(FPDecimal::ZERO), FPDecimal::ONE, options).is_err());
+        // errors of the function itself are passed through
+        assert!(brent(|_| Err(StdError::generic_err("oracle down")), FPDecimal::ZERO, FPDecimal::ONE, options).is_err());
+        let zero_tolerance = SolverOptions {
+            tolerance: FPDecimal::ZERO,
+            ..options
+        };
+        assert!(bisection(square_minus_two, FPDecimal::ZERO, FPDecimal::TWO, zero_tolerance).is_err());
+    }
+
+    #[test]
+    fn reports_overflow_instead_of_panicking() {
+        let options = SolverOptions::default();
+        let identity = |x: FPDecimal| Ok(x);
+        // the width of this bracket does not fit an FPDecimal
+        assert!(brent(identity, FPDecimal::MIN, FPDecimal::MAX, options).is_err());
+        assert!(golden_section_minimize(identity, FPDecimal::MIN, FPDecimal::MAX, options).is_err());
+        // nor does the sum of these bounds
+        let near_max = |x: FPDecimal| Ok(x - (FPDecimal::MAX - FPDecimal::ONE));
+        assert!(bisection(near_max, FPDecimal::MAX - FPDecimal::TWO, FPDecimal::MAX, options).is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use cosmwasm_std::{StdError, StdResult};
+
+use crate::fp_decimal::FPDecimal;
+use crate::matrix::FPMatrix;
+use crate::vector::FPVector;
+
+fn ensure_len(values: &[FPDecimal], min_len: usize) -> StdResult<()> {
+    if values.len() < min_len {
+        return Err(StdError::generic_err(format!(
+            "At least {min_len} values are required, got {}",
+            values.len()
+        )));
+    }
+    Ok(())
+}
+
+fn ensure_same_len(xs: &[FPDecimal], ys: &[FPDecimal]) -> StdResult<()> {
+    if xs.len() != ys.len() {
+        return Err(StdError::generic_err(format!("Series lengths differ: {} and {}", xs.len(), ys.len())));
+    }
+    Ok(())
+}
+
+fn count(len: usize) -> FPDecimal {
+    FPDecimal::from(len as u128)
+}
+
+fn checked_sum(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    Ok(values.iter().try_fold(FPDecimal::zero(), |acc, &value| acc.checked_add(value))?)
+}
+
+pub fn mean(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    ensure_len(values, 1)?;
+    checked_sum(values)?.checked_div(count(values.len()))
+}
+
+// sum of squared deviations from the mean, computed in two passes to avoid cancellation
+fn squared_deviations(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    let mean = mean(values)?;
+    values.iter().try_fold(FPDecimal::zero(), |acc, &value| {
+        let deviation = value.checked_sub(mean)?;
+        Ok(acc.checked_add(deviation.checked_mul(deviation)?)?)
+    })
+}
+
+/// Variance of a whole population, divided by n
+pub fn population_variance(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    squared_deviations(values)?.checked_div(count(values.len()))
+}
+
+/// Unbiased variance estimate of a sample, divided by n - 1
+pub fn sample_variance(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    ensure_len(values, 2)?;
+    squared_deviations(values)?.checked_div(count(values.len() - 1))
+}
+
+pub fn population_std_dev(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    population_variance(values)?.checked_sqrt()
+}
+
+pub fn sample_std_dev(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    sample_variance(values)?.checked_sqrt()
+}
+
+pub fn median(values: &[FPDecimal]) -> StdResult<FPDecimal> {
+    percentile(values, FPDecimal::from(50u128))
+}
+
+/// Percentile `p` in [0, 100], linearly interpolated between the closest ranks
+pub fn percentile(values: &[FPDecimal], p: FPDecimal) -> StdResult<FPDecimal> {
+    ensure_len(values, 1)?;
+    if p.is_negative() || p > FPDecimal::from(100u128) {
+        return Err(StdError::generic_err(format!("Percentile must be between 0 and 100, got {p}")));
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort();
+
+    let rank = p.checked_div(FPDecimal::from(100u128))?.checked_mul(count(sorted.len() - 1))?;
+    let lower = rank.checked_to_u128()? as usize;
+    match sorted.get(lower + 1) {
+        Some(&upper) => {
+            let step = upper.checked_sub(sorted[lower])?.checked_mul(rank.fraction())?;
+            Ok(sorted[lower].checked_add(step)?)
+        }
+        None => Ok(sorted[lower]),
+    }
+}
+
+/// Unbiased covariance estimate of two samples of equal length
+pub fn sample_covariance(xs: &[FPDecimal], ys: &[FPDecimal]) -> StdResult<FPDecimal> {
+    ensure_same_len(xs, ys)?;
+    ensure_len(xs, 2)?;
+    let (mean_x, mean_y) = (mean(xs)?, mean(ys)?);
+    let products = xs.iter().zip(ys).try_fold(FPDecimal::zero(), |acc, (&x, &y)| {
+        let product = x.checked_sub(mean_x)?.checked_mul(y.checked_sub(mean_y)?)?;
+        acc.checked_add(product)
+    })?;
+    products.checked_div(count(xs.len() - 1))
+}
+
+/// Pearson correlation coefficient, fails if either series is constant
+pub fn correlation(xs: &[FPDecimal], ys: &[FPDecimal]) -> StdResult<FPDecimal> {
+    let covariance = sample_covariance(xs, ys)?;
+    let deviations = sample_std_dev(xs)?.checked_mul(sample_std_dev(ys)?)?;
+    if deviations.is_zero() {
+        return Err(StdError::generic_err("Correlation is undefined for a constant series"));
+    }
+    covariance.checked_div(deviations)
+}
+
+/// Sample covariance matrix of equally long series, one series per row and column
+pub fn covariance_matrix(series: &[Vec<FPDecimal>]) -> StdResult<FPMatrix> {
+    let size = series.len();
+    let mut values = vec![FPDecimal::zero(); size * size];
+    for i in 0..size {
+        for j in i..size {
+            let covariance = sample_covariance(&series[i], &series[j])?;
+            values[i * size + j] = covariance;
+            values[j * size + i] = covariance;
+        }
+    }
+    FPMatrix::new(size, size, values)
+}
+
+/// Fully invested weights with the lowest variance under `covariance`, C^-1 * 1 / (1' * C^-1 * 1).
+/// Weights are unconstrained, so short positions come out negative.
+pub fn minimum_variance_weights(covariance: &FPMatrix) -> StdResult<FPVector> {
+    let raw = covariance.solve(&FPVector::new(vec![FPDecimal::ONE; covariance.rows()]))?;
+    let total = checked_sum(raw.as_slice())?;
+    if total.is_zero() {
+        return Err(StdError::generic_err("Minimum variance weights are undefined for this covariance"));
+    }
+    raw.as_slice()
+        .iter()
+        .map(|weight| weight.checked_div(total))
+        .collect::<StdResult<_>>()
+        .map(FPVector::new)
+}
+
+/// Exponentially weighted moving average of every prefix of `values`, seeded with the first value.
+/// `alpha` in (0, 1] is the weight of the newest value.
+pub fn ewma(values: &[FPDecimal], alpha: FPDecimal) -> StdResult<Vec<FPDecimal>> {
+    ensure_len(values, 1)?;
+    if alpha <= FPDecimal::zero() || alpha > FPDecimal::ONE {
+        return Err(StdError::generic_err(format!("EWMA alpha must be in (0, 1], got {alpha}")));
+    }
+
+    let mut average = values[0];
+    let mut averages = Vec::with_capacity(values.len());
+    averages.push(average);
+    for &value in &values[1..] {
+        let decayed = FPDecimal::ONE.checked_sub(alpha)?.checked_mul(average)?;
+        average = alpha.checked_mul(value)?.checked_add(decayed)?;
+        averages.push(average);
+    }
+    Ok(averages)
+}
+
+/// Latest value of `ewma`
+pub fn ema(values: &[FPDecimal], alpha: FPDecimal) -> StdResult<FPDecimal> {
+    Ok(*ewma(values, alpha)?.last().unwrap())
+}
+
+/// The usual EMA smoothing factor for a window of `periods` values, 2 / (periods + 1)
+pub fn ema_alpha(periods: u32) -> FPDecimal {
+    FPDecimal::TWO / FPDecimal::from(periods as u128 + 1)
+}
+
+/// ln(p[i] / p[i - 1]) for consecutive prices, which must all be positive
+pub fn log_returns(prices: &[FPDecimal]) -> StdResult<Vec<FPDecimal>> {
+    ensure_len(prices, 2)?;
+    prices.windows(2).map(|pair| pair[1].checked_div(pair[0])?.checked_ln()).collect()
+}
+
+/// Sample standard deviation of the log returns, scaled by sqrt(`periods_per_year`)
+/// (e.g. 365 for daily prices, 8760 for hourly ones)
+pub fn annualized_volatility(prices: &[FPDecimal], periods_per_year: FPDecimal) -> StdResult<FPDecimal> {
+    if periods_per_year <= FPDecimal::zero() {
+        return Err(StdError::generic_err("Periods per year must be positive"));
+    }
+    let returns = log_returns(prices)?;
+    Ok(sample_std_dev(&returns)?.checked_mul(periods_per_year.checked_sqrt()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::*;
+    use crate::test_utils::{dec, decs};
+    use crate::FPDecimal;
+
+    #[test]
+    fn moments() {
+        let values = decs(&["2", "4", "4", "4", "5", "5", "7", "9"]);
+        assert_eq!(mean(&values).unwrap(), dec("5"));
+        assert_eq!(population_variance(&values).unwrap(), dec("4"));
+        assert_eq!(population_std_dev(&values).unwrap(), dec("2"));
+        assert_eq!(sample_variance(&values).unwrap(), dec("4.571428571428571428"));
+        assert!(sample_std_dev(&values).unwrap().abs_diff(&dec("2.138089935299395077")) < dec("0.000000000001"));
+
+        assert!(mean(&[]).is_err());
+        assert!(sample_variance(&decs(&["1"])).is_err());
+    }
+
+    #[test]
+    fn overflow_returns_errors() {
+        let extremes = [FPDecimal::MIN, FPDecimal::MAX];
+        assert!(mean(&[FPDecimal::MAX, FPDecimal::MAX]).is_err());
+        assert!(population_variance(&extremes).is_err());
+        assert!(sample_covariance(&extremes, &extremes).is_err());
+        assert!(percentile(&extremes, dec("50")).is_err());
+    }
+
+    #[test]
+    fn median_and_percentiles() {
+        let values = decs(&["15", "20", "35", "40", "50"]);
+        assert_eq!(median(&values).unwrap(), dec("35"));
+        assert_eq!(median(&decs(&["4", "1", "3", "2"])).unwrap(), dec("2.5"));
+        assert_eq!(percentile(&values, dec("0")).unwrap(), dec("15"));
+        assert_eq!(percentile(&values, dec("100")).unwrap(), dec("50"));
+        assert_eq!(percentile(&values, dec("40")).unwrap(), dec("29"));
+        assert!(percentile(&values, dec("101")).is_err());
+    }
+
+    #[test]
+    fn covariance_and_correlation() {
+        let xs = decs(&["1", "2", "3", "4"]);
+        let ys = decs(&["2", "4", "6", "8"]);
+        assert_eq!(sample_covariance(&xs, &ys).unwrap(), dec("3.333333333333333333"));
+        assert!(correlation(&xs, &ys).unwrap().abs_diff(&FPDecimal::ONE) < dec("0.000000000001"));
+
+        let inverse = decs(&["4", "3", "2", "1"]);
+        assert!(correlation(&xs, &inverse).unwrap().abs_diff(&dec("-1")) < dec("0.000000000001"));
+        assert!(correlation(&xs, &decs(&["1", "1", "1", "1"])).is_err());
+        assert!(sample_covariance(&xs, &ys[1..]).is_err());
+    }
+
+    #[test]
+    fn covariance_and_minimum_variance() {
+        let covariance = covariance_matrix(&[decs(&["1", "2", "3", "4"]), decs(&["2", "4", "6", "8"]), decs(&["4", "3", "2", "1"])]).unwrap();
+        assert_eq!(covariance[(0, 1)], dec("3.333333333333333333"));
+        assert_eq!(covariance[(2, 0)], dec("-1.666666666666666666"));
+        assert_eq!(covariance, covariance.transpose());
+        assert!(covariance_matrix(&[decs(&["1", "2"]), decs(&["1"])]).is_err());
+
+        // uncorrelated assets are weighted by inverse variance
+        let diagonal = FPMatrix::new(2, 2, decs(&["1", "0", "0", "4"])).unwrap();
+        assert_eq!(minimum_variance_weights(&diagonal).unwrap(), FPVector::new(decs(&["0.8", "0.2"])));
+        // perfectly correlated series have a singular covariance
+        assert!(minimum_variance_weights(&covariance).is_err());
+        // series that differ only past the 15th digit are nearly singular, which must not yield garbage weights
+        let nearly_singular = covariance_matrix(&[decs(&["1", "2", "3", "4"]), decs(&["1", "2", "3", "4.000000000000001"])]).unwrap();
+        assert!(minimum_variance_weights(&nearly_singular).is_err());
+    }
+
+    #[test]
+    fn exponential_averages() {
+        let values = decs(&["10", "20", "30"]);
+        assert_eq!(ewma(&values, dec("0.5")).unwrap(), decs(&["10", "15", "22.5"]));
+        assert_eq!(ema(&values, FPDecimal::ONE).unwrap(), dec("30"));
+        assert_eq!(ema_alpha(3), dec("0.5"));
+        assert!(ewma(&values, FPDecimal::zero()).is_err());
+    }
+
+    #[test]
+    fn returns_and_volatility() {
+        let prices = decs(&["100", "110", "99"]);
+        let returns = log_returns(&prices).unwrap();
+        assert!(returns[0].abs_diff(&dec("0.095310179804324860")) < dec("0.000000000001"));
+        assert!(returns[1].abs_diff(&dec("-0.105360515657826301")) < dec("0.000000000001"));
+
+        // sample std dev of the two returns is |r0 - r1| / sqrt(2)
+        let volatility = annualized_volatility(&prices, dec("365")).unwrap();
+        assert!(volatility.abs_diff(&dec("2.710911813975248656")) < dec("0.000000000001"));
+
+        assert!(log_returns(&decs(&["1", "0"])).is_err());
+        assert!(annualized_volatility(&prices, FPDecimal::zero()).is_err());
+    }
+}
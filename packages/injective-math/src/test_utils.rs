@@ -0,0 +1,37 @@
+//! Helpers shared by the unit tests of this crate
+
+use std::str::FromStr;
+
+use crate::FPDecimal;
+
+pub(crate) fn dec(value: &str) -> FPDecimal {
+    FPDecimal::from_str(value).unwrap()
+}
+
+pub(crate) fn decs(values: &[&str]) -> Vec<FPDecimal> {
+    values.iter().map(|value| dec(value)).collect()
+}
+
+/// Asserts that `actual` is within `tolerance` times the magnitude of `expected` of it
+pub(crate) fn assert_close_relative(actual: FPDecimal, expected: &str, tolerance: &str) {
+    let expected = dec(expected);
+    assert!(
+        actual.abs_diff(&expected) <= expected.abs() * dec(tolerance),
+        "{:?}",
+        (actual.to_string(), expected.to_string(), tolerance)
+    );
+}
+
+/// Asserts that `actual` is within 1e-12 of `expected`
+pub(crate) fn assert_close(actual: FPDecimal, expected: &str) {
+    assert_close_within(actual, expected, "0.000000000001");
+}
+
+/// Asserts that `actual` is within `tolerance` of `expected`
+pub(crate) fn assert_close_within(actual: FPDecimal, expected: &str, tolerance: &str) {
+    assert!(
+        actual.abs_diff(&dec(expected)) <= dec(tolerance),
+        "{:?}",
+        (actual.to_string(), expected, tolerance)
+    );
+}
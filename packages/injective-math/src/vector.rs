@@ -1,38 +1,174 @@
+use cosmwasm_std::{StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::ops::Index;
+
 use crate::fp_decimal::FPDecimal;
 
+/// A vector of FPDecimal whose element-wise operations fail on operands of different lengths
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(transparent)]
+pub struct FPVector(Vec<FPDecimal>);
+
+impl FPVector {
+    pub fn new(values: Vec<FPDecimal>) -> FPVector {
+        FPVector(values)
+    }
+
+    pub fn zeros(len: usize) -> FPVector {
+        FPVector(vec![FPDecimal::zero(); len])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[FPDecimal] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<FPDecimal> {
+        self.0
+    }
+
+    pub fn sum(&self) -> FPDecimal {
+        sum(&self.0)
+    }
+
+    pub fn dot(&self, other: &FPVector) -> StdResult<FPDecimal> {
+        Ok(sum(&zip_with(&self.0, &other.0, |a, b| a * b)?))
+    }
+
+    /// Element-wise product
+    pub fn mul(&self, other: &FPVector) -> StdResult<FPVector> {
+        zip_with(&self.0, &other.0, |a, b| a * b).map(FPVector)
+    }
+
+    pub fn add(&self, other: &FPVector) -> StdResult<FPVector> {
+        zip_with(&self.0, &other.0, |a, b| a + b).map(FPVector)
+    }
+
+    pub fn sub(&self, other: &FPVector) -> StdResult<FPVector> {
+        zip_with(&self.0, &other.0, |a, b| a - b).map(FPVector)
+    }
+
+    pub fn mul_const(&self, other: FPDecimal) -> FPVector {
+        FPVector(mul_const(&self.0, other))
+    }
+
+    pub fn div_const(&self, other: FPDecimal) -> FPVector {
+        FPVector(div_const(&self.0, other))
+    }
+
+    pub fn abs(&self) -> FPVector {
+        FPVector(abs(&self.0))
+    }
+}
+
+impl From<Vec<FPDecimal>> for FPVector {
+    fn from(values: Vec<FPDecimal>) -> FPVector {
+        FPVector(values)
+    }
+}
+
+impl From<&[FPDecimal]> for FPVector {
+    fn from(values: &[FPDecimal]) -> FPVector {
+        FPVector(values.to_vec())
+    }
+}
+
+impl From<FPVector> for Vec<FPDecimal> {
+    fn from(vector: FPVector) -> Vec<FPDecimal> {
+        vector.0
+    }
+}
+
+impl Index<usize> for FPVector {
+    type Output = FPDecimal;
+
+    fn index(&self, index: usize) -> &FPDecimal {
+        &self.0[index]
+    }
+}
+
+fn zip_with(vec: &[FPDecimal], other: &[FPDecimal], f: impl Fn(FPDecimal, FPDecimal) -> FPDecimal) -> StdResult<Vec<FPDecimal>> {
+    if vec.len() != other.len() {
+        return Err(StdError::generic_err(format!("Vector lengths differ: {} and {}", vec.len(), other.len())));
+    }
+    Ok(vec.iter().zip(other).map(|(&a, &b)| f(a, b)).collect())
+}
+
+// the free functions keep their infallible signatures, so a length mismatch is a bug in the caller
+fn expect_same_len<T>(result: StdResult<T>) -> T {
+    result.unwrap_or_else(|err| panic!("{}", err))
+}
+
 pub fn sum(vec: &[FPDecimal]) -> FPDecimal {
     vec.iter().fold(FPDecimal::zero(), |acc, &el| acc + el)
 }
 
+/// Panics if the lengths differ, see [`FPVector::dot`]
 pub fn dot(vec: &[FPDecimal], other: &[FPDecimal]) -> FPDecimal {
-    let mut sum = FPDecimal::zero();
-    let mul_result: Vec<FPDecimal> = mul(vec, other);
-    for item in mul_result {
-        sum += item;
-    }
-    sum
+    sum(&mul(vec, other))
 }
 
+/// Panics if the lengths differ, see [`FPVector::mul`]
 pub fn mul(vec: &[FPDecimal], other: &[FPDecimal]) -> Vec<FPDecimal> {
-    vec.iter().zip(other).map(|(&i1, &i2)| i1 * i2).collect()
+    expect_same_len(zip_with(vec, other, |a, b| a * b))
 }
 
 pub fn mul_const(vec: &[FPDecimal], other: FPDecimal) -> Vec<FPDecimal> {
-    vec.iter().map(|&i| i * other).collect()
+    vec.iter().map(|&value| value * other).collect()
 }
 
 pub fn div_const(vec: &[FPDecimal], other: FPDecimal) -> Vec<FPDecimal> {
-    vec.iter().map(|&i| i / other).collect()
+    vec.iter().map(|&value| value / other).collect()
 }
 
+/// Panics if the lengths differ, see [`FPVector::add`]
 pub fn add(vec: &[FPDecimal], other: &[FPDecimal]) -> Vec<FPDecimal> {
-    vec.iter().zip(other).map(|(&i1, &i2)| i1 + i2).collect()
+    expect_same_len(zip_with(vec, other, |a, b| a + b))
 }
 
+/// Panics if the lengths differ, see [`FPVector::sub`]
 pub fn sub(vec: &[FPDecimal], other: &[FPDecimal]) -> Vec<FPDecimal> {
-    vec.iter().zip(other).map(|(&i1, &i2)| i1 - i2).collect()
+    expect_same_len(zip_with(vec, other, |a, b| a - b))
 }
 
 pub fn abs(vec: &[FPDecimal]) -> Vec<FPDecimal> {
-    vec.iter().map(|&i| i.abs()).collect()
+    vec.iter().map(|&value| value.abs()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vector::*;
+    use crate::FPDecimal;
+
+    fn ints(values: &[i128]) -> Vec<FPDecimal> {
+        values.iter().map(|&value| FPDecimal::from(value)).collect()
+    }
+
+    #[test]
+    fn checks_dimensions() {
+        let a = FPVector::new(ints(&[1, 2, 3]));
+        let b = FPVector::new(ints(&[4, -5, 6]));
+        assert_eq!(a.dot(&b).unwrap(), FPDecimal::from(12u128));
+        assert_eq!(a.sub(&b).unwrap(), FPVector::new(ints(&[-3, 7, -3])));
+        assert_eq!(b.abs().sum(), FPDecimal::from(15u128));
+        assert!(a.dot(&FPVector::new(ints(&[1, 2]))).is_err());
+        assert!(a.add(&FPVector::zeros(4)).is_err());
+
+        assert_eq!(dot(&ints(&[1, 2]), &ints(&[3, 4])), FPDecimal::from(11u128));
+        assert_eq!(mul_const(&ints(&[1, -2]), FPDecimal::TWO), ints(&[2, -4]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector lengths differ: 2 and 1")]
+    fn free_functions_panic_on_mismatch() {
+        dot(&ints(&[1, 2]), &ints(&[3]));
+    }
 }
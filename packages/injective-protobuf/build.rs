@@ -1,6 +1,18 @@
+use std::fs;
+
 use protobuf_codegen_pure::Customize;
 
-fn main() {
+const COIN: &str = "third_party/proto/cosmos/base/v1beta1/coin.proto";
+const PAGINATION: &str = "third_party/proto/cosmos/base/query/v1beta1/pagination.proto";
+
+/// Generates exactly `inputs` into `src/proto/<module>`; imports are only resolved, not generated,
+/// so every imported file a tree refers to (e.g. `COIN`) has to be listed as an input too.
+/// rust-protobuf names modules after file stems, so each chain module gets its own tree
+/// and shared Cosmos SDK types (e.g. `coin`) are generated once per tree.
+fn generate(module: &str, inputs: &[&str]) {
+    let out_dir = format!("src/proto/{module}");
+    fs::create_dir_all(&out_dir).expect("Failed to create protobuf output directory.");
+
     let customizer = Customize {
         gen_mod_rs: Some(true),
         lite_runtime: Some(true),
@@ -8,16 +20,154 @@ fn main() {
     };
 
     protobuf_codegen_pure::Codegen::new()
-        .out_dir("src/proto")
-        .inputs([
-            "third_party/proto/cosmos/base/v1beta1/coin.proto",
-            "proto/injective/exchange/v1beta1/exchange.proto",
-            "proto/injective/exchange/v1beta1/tx.proto",
-            "proto/injective/oracle/v1beta1/oracle.proto",
-            "third_party/proto/cosmos/distribution/v1beta1/distribution.proto",
-        ])
+        .out_dir(&out_dir)
+        .inputs(inputs)
         .includes(["proto", "third_party/proto"])
         .customize(customizer)
         .run()
         .expect("Protobuf codegen failed.");
 }
+
+/// Cross-file references to a clashing file stem have to be re-pointed by hand
+fn redirect_type(file: &str, from: &str, to: &str) {
+    let source = fs::read_to_string(file).expect("Generated file missing.");
+    assert!(source.contains(from), "{} no longer refers to {}, update redirect_type.", file, from);
+    fs::write(file, source.replace(from, to)).expect("Failed to rewrite generated file.");
+}
+
+fn main() {
+    generate(
+        "exchange",
+        &[
+            COIN,
+            "third_party/proto/cosmos/distribution/v1beta1/distribution.proto",
+            "proto/injective/oracle/v1beta1/oracle.proto",
+            "proto/injective/exchange/v1beta1/exchange.proto",
+            "proto/injective/exchange/v1beta1/genesis.proto",
+            "proto/injective/exchange/v1beta1/authz.proto",
+            "proto/injective/exchange/v1beta1/tx.proto",
+            "proto/injective/exchange/v1beta1/query.proto",
+        ],
+    );
+
+    generate(
+        "oracle",
+        &[
+            COIN,
+            "proto/injective/oracle/v1beta1/oracle.proto",
+            "proto/injective/oracle/v1beta1/genesis.proto",
+            "proto/injective/oracle/v1beta1/tx.proto",
+            "proto/injective/oracle/v1beta1/query.proto",
+        ],
+    );
+
+    generate(
+        "tokenfactory",
+        &[
+            COIN,
+            PAGINATION,
+            "third_party/proto/cosmos/bank/v1beta1/bank.proto",
+            "proto/injective/tokenfactory/v1beta1/authorityMetadata.proto",
+            "proto/injective/tokenfactory/v1beta1/params.proto",
+            "proto/injective/tokenfactory/v1beta1/genesis.proto",
+            "proto/injective/tokenfactory/v1beta1/tx.proto",
+            "proto/injective/tokenfactory/v1beta1/query.proto",
+        ],
+    );
+
+    generate(
+        "insurance",
+        &[
+            COIN,
+            "proto/injective/oracle/v1beta1/oracle.proto",
+            "proto/injective/insurance/v1beta1/insurance.proto",
+            "proto/injective/insurance/v1beta1/genesis.proto",
+            "proto/injective/insurance/v1beta1/tx.proto",
+            "proto/injective/insurance/v1beta1/query.proto",
+        ],
+    );
+
+    generate(
+        "auction",
+        &[
+            COIN,
+            "proto/injective/auction/v1beta1/auction.proto",
+            "proto/injective/auction/v1beta1/genesis.proto",
+            "proto/injective/auction/v1beta1/tx.proto",
+            "proto/injective/auction/v1beta1/query.proto",
+        ],
+    );
+
+    generate(
+        "peggy",
+        &[
+            COIN,
+            "proto/injective/peggy/v1/attestation.proto",
+            "proto/injective/peggy/v1/batch.proto",
+            "proto/injective/peggy/v1/params.proto",
+            "proto/injective/peggy/v1/pool.proto",
+            "proto/injective/peggy/v1/types.proto",
+            "proto/injective/peggy/v1/genesis.proto",
+            "proto/injective/peggy/v1/msgs.proto",
+            "proto/injective/peggy/v1/query.proto",
+        ],
+    );
+
+    generate(
+        "ocr",
+        &[
+            COIN,
+            "proto/injective/ocr/v1beta1/ocr.proto",
+            "proto/injective/ocr/v1beta1/genesis.proto",
+            "proto/injective/ocr/v1beta1/tx.proto",
+            "proto/injective/ocr/v1beta1/query.proto",
+        ],
+    );
+
+    generate(
+        "cosmwasm",
+        &[
+            COIN,
+            "third_party/proto/cosmwasm/wasm/v1/types.proto",
+            "third_party/proto/cosmwasm/wasm/v1/proposal.proto",
+        ],
+    );
+
+    // wasmx proposal.proto refers to the cosmwasm proposal.proto types, which live in the cosmwasm tree
+    generate(
+        "wasmx",
+        &[
+            "proto/injective/wasmx/v1/proposal.proto",
+            "proto/injective/wasmx/v1/wasmx.proto",
+            "proto/injective/wasmx/v1/genesis.proto",
+            "proto/injective/wasmx/v1/tx.proto",
+            "proto/injective/wasmx/v1/query.proto",
+        ],
+    );
+    redirect_type(
+        "src/proto/wasmx/proposal.rs",
+        "super::proposal::StoreCodeProposal",
+        "crate::proto::cosmwasm::proposal::StoreCodeProposal",
+    );
+
+    generate(
+        "authz",
+        &[
+            "third_party/proto/cosmos/authz/v1beta1/authz.proto",
+            "third_party/proto/cosmos/authz/v1beta1/tx.proto",
+        ],
+    );
+
+    generate(
+        "ibc",
+        &[
+            COIN,
+            PAGINATION,
+            "third_party/proto/cosmos/upgrade/v1beta1/upgrade.proto",
+            "third_party/proto/ibc/core/client/v1/client.proto",
+            "third_party/proto/ibc/applications/transfer/v1/transfer.proto",
+            "third_party/proto/ibc/applications/transfer/v1/tx.proto",
+            "third_party/proto/ibc/applications/transfer/v1/query.proto",
+        ],
+    );
+}
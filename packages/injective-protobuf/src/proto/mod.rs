@@ -0,0 +1,18 @@
+//! Generated protobuf bindings, one tree per chain module, e.g. `proto::exchange::tx::MsgDeposit`.
+//! Each tree carries its own copy of the Cosmos SDK types it depends on, such as `coin`.
+
+pub mod auction;
+pub mod cosmwasm;
+pub mod exchange;
+pub mod insurance;
+pub mod ocr;
+pub mod oracle;
+pub mod peggy;
+pub mod tokenfactory;
+pub mod wasmx;
+
+pub mod authz;
+pub mod ibc;
+
+// paths from before the split into one tree per module, where they don't clash with a module tree
+pub use exchange::{coin, distribution, tx};